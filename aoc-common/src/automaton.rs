@@ -0,0 +1,455 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::grid::Grid;
+
+/// What lies beyond the edges of the grid.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Boundary<T> {
+    /// Nothing: cells on the edge just have fewer neighbours.
+    Finite,
+    /// The grid is a torus, and the far edge is next to the near one.
+    Wrapping,
+    /// The grid goes on forever in every direction, filled with a
+    /// background state that evolves along with everything else. The grid
+    /// grows by a cell on every side each phase, as the cells next to it can
+    /// end up different from the background.
+    Infinite(T),
+}
+
+/// How the cells of an automaton change from one generation to the next.
+///
+/// A generation is made of one or more phases, each applied to every cell at
+/// once, and each phase looks at the cells as they were left by the phase
+/// before. Rules only look at the cells immediately around them.
+pub trait Rule<T> {
+    /// How many phases make up a generation.
+    const PHASES: usize = 1;
+
+    /// Whether `phase` is repeated until it stops changing anything, for
+    /// rules where changes cascade.
+    fn settles(&self, _phase: usize) -> bool {
+        false
+    }
+
+    /// The state the cell in the middle of `view` has after `phase`.
+    fn next(&self, phase: usize, view: &View<T>) -> T;
+}
+
+/// A cell and its surroundings, with the boundary taken into account.
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    boundary: &'a Boundary<T>,
+    x: isize,
+    y: isize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn position(&self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+
+    pub fn cell(&self) -> &'a T {
+        self.at(0, 0).unwrap()
+    }
+
+    /// The cell `(dx, dy)` away, or `None` if that's off the edge of a
+    /// finite grid.
+    pub fn at(&self, dx: isize, dy: isize) -> Option<&'a T> {
+        let (x, y) = (self.x + dx, self.y + dy);
+        let (width, height) = (self.grid.width() as isize, self.grid.height() as isize);
+
+        if (0..width).contains(&x) && (0..height).contains(&y) {
+            return Some(&self.grid[(x as usize, y as usize)]);
+        }
+
+        match self.boundary {
+            Boundary::Finite => None,
+            Boundary::Wrapping => {
+                Some(&self.grid[(x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)])
+            }
+            Boundary::Infinite(background) => Some(background),
+        }
+    }
+
+    /// The cells directly above, left, right and below.
+    pub fn neighbours4(&self) -> impl Iterator<Item = &'a T> + '_ {
+        [(0, -1), (-1, 0), (1, 0), (0, 1)]
+            .into_iter()
+            .filter_map(|(dx, dy)| self.at(dx, dy))
+    }
+
+    /// The cells all around, including the diagonals.
+    pub fn neighbours8(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&delta| delta != (0, 0))
+            .filter_map(|(dx, dy)| self.at(dx, dy))
+    }
+}
+
+/// A repeating run of generations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first generation that came round again.
+    pub start: usize,
+    /// How many generations it takes to come round. A fixed point is a cycle
+    /// with a period of 1.
+    pub period: usize,
+}
+
+/// The cells of an automaton, where they are, and what's beyond them.
+#[derive(Clone)]
+struct Cells<T> {
+    grid: Grid<T>,
+    boundary: Boundary<T>,
+    /// Where the grid's top left cell is, which moves up and left as an
+    /// infinite grid grows.
+    origin: (isize, isize),
+}
+
+impl<T: Clone + Eq + Hash> Cells<T> {
+    /// The cells after a generation of `rule`.
+    fn advance<R: Rule<T>>(&self, rule: &R) -> Self {
+        let mut next: Option<Self> = None;
+        for phase in 0..R::PHASES {
+            loop {
+                let (cells, changed) = next.as_ref().unwrap_or(self).apply(rule, phase);
+                next = Some(cells);
+                if !changed || !rule.settles(phase) {
+                    break;
+                }
+            }
+        }
+        next.unwrap_or_else(|| self.clone())
+    }
+
+    /// Applies one phase of `rule` to every cell, returning the new cells
+    /// and whether anything changed.
+    fn apply<R: Rule<T>>(&self, rule: &R, phase: usize) -> (Self, bool) {
+        // An infinite grid grows by a cell on each side, so new cell (x, y)
+        // is old cell (x - 1, y - 1).
+        let (grow, origin) = match self.boundary {
+            Boundary::Infinite(_) => (2, -1),
+            _ => (0, 0),
+        };
+        let view = |x: isize, y: isize| View {
+            grid: &self.grid,
+            boundary: &self.boundary,
+            x,
+            y,
+        };
+
+        // Cells that were past the edge of an infinite grid were background,
+        // so that's what the new ones on its border are compared against.
+        let mut changed = false;
+        let grid = Grid::from_rows((0..self.grid.height() + grow).map(|y| {
+            (0..self.grid.width() + grow)
+                .map(|x| {
+                    let view = view(x as isize + origin, y as isize + origin);
+                    let cell = rule.next(phase, &view);
+                    changed |= &cell != view.cell();
+                    cell
+                })
+                .collect()
+        }));
+
+        let boundary = match &self.boundary {
+            // Two cells out from the grid, everything in sight is background.
+            Boundary::Infinite(_) => Boundary::Infinite(rule.next(phase, &view(-2, -2))),
+            boundary => boundary.clone(),
+        };
+
+        changed |= boundary != self.boundary;
+        let cells = Self {
+            grid,
+            boundary,
+            origin: (self.origin.0 + origin, self.origin.1 + origin),
+        };
+        (cells, changed)
+    }
+
+    /// The part of the grid that makes up the state: all of it, or for an
+    /// infinite grid just the cells that aren't background, so that it
+    /// matches an earlier generation with the same cells in the same places
+    /// however much the grid has grown since. Gives where its top left cell
+    /// is, its width and height, and its cells in reading order.
+    fn state(&self) -> ((isize, isize), (usize, usize), impl Iterator<Item = &T>) {
+        let (left, top, right, bottom) = match &self.boundary {
+            Boundary::Infinite(background) => self
+                .grid
+                .positions()
+                .filter(|&p| &self.grid[p] != background)
+                .fold(None, |extent, (x, y)| match extent {
+                    None => Some((x, y, x + 1, y + 1)),
+                    Some((l, t, r, b)) => Some((l.min(x), t.min(y), r.max(x + 1), b.max(y + 1))),
+                })
+                .unwrap_or((0, 0, 0, 0)),
+            _ => (0, 0, self.grid.width(), self.grid.height()),
+        };
+
+        let position = if left == right {
+            (0, 0)
+        } else {
+            (self.origin.0 + left as isize, self.origin.1 + top as isize)
+        };
+        let cells = (top..bottom).flat_map(move |y| &self.grid.row(y)[left..right]);
+        (position, (right - left, bottom - top), cells)
+    }
+
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        let (position, size, cells) = self.state();
+        (position, size, &self.boundary).hash(&mut hasher);
+        cells.for_each(|cell| cell.hash(&mut hasher));
+        hasher.finish()
+    }
+
+    fn same_state(&self, other: &Self) -> bool {
+        let (position, size, cells) = self.state();
+        let (other_position, other_size, other_cells) = other.state();
+        self.boundary == other.boundary
+            && (position, size) == (other_position, other_size)
+            && cells.eq(other_cells)
+    }
+}
+
+/// A grid of cells that all change at once, generation by generation.
+///
+/// Every generation is remembered by a hash of its state, so that the
+/// automaton can tell when it has come back to a state it has already been
+/// in. Hashes can collide, so a match is checked by running the rule again
+/// from the start, which assumes the same rule is used every step.
+pub struct Automaton<T> {
+    cells: Cells<T>,
+    start: Cells<T>,
+    generation: usize,
+    /// The generations with each hash.
+    history: HashMap<u64, Vec<usize>>,
+    cycle: Option<Cycle>,
+}
+
+impl<T: Clone + Eq + Hash> Automaton<T> {
+    pub fn new(grid: Grid<T>, boundary: Boundary<T>) -> Self {
+        let cells = Cells {
+            // The boundary decides what's past the edges, not the grid.
+            grid: Grid::from_rows(grid.rows().map(|row| row.to_vec())),
+            boundary,
+            origin: (0, 0),
+        };
+        Self {
+            history: HashMap::from([(cells.fingerprint(), vec![0])]),
+            start: cells.clone(),
+            cells,
+            generation: 0,
+            cycle: None,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.cells.grid
+    }
+
+    /// The state of every cell outside the grid, for an infinite automaton.
+    pub fn background(&self) -> Option<&T> {
+        match &self.cells.boundary {
+            Boundary::Infinite(background) => Some(background),
+            _ => None,
+        }
+    }
+
+    /// How many generations have passed.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// The cycle the automaton has fallen into, if it has come back round to
+    /// an earlier generation yet.
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// Moves on a generation, returning whether it's different from the one
+    /// before.
+    pub fn step<R: Rule<T>>(&mut self, rule: &R) -> bool {
+        let next = self.cells.advance(rule);
+        let changed = !next.same_state(&self.cells);
+
+        self.cells = next;
+        self.generation += 1;
+        self.remember(rule);
+        changed
+    }
+
+    /// Moves on `generations` generations.
+    pub fn run<R: Rule<T>>(&mut self, rule: &R, generations: usize) {
+        for _ in 0..generations {
+            self.step(rule);
+        }
+    }
+
+    /// Steps until a generation is the same as the one before, returning the
+    /// number of that generation.
+    pub fn run_until_stable<R: Rule<T>>(&mut self, rule: &R) -> usize {
+        while self.step(rule) {}
+        self.generation
+    }
+
+    /// Steps until the automaton comes back to a generation it has been in
+    /// before, which could be never.
+    pub fn run_until_cycle<R: Rule<T>>(&mut self, rule: &R) -> Cycle {
+        loop {
+            if let Some(cycle) = self.cycle {
+                return cycle;
+            }
+            self.step(rule);
+        }
+    }
+
+    fn remember<R: Rule<T>>(&mut self, rule: &R) {
+        if self.cycle.is_some() {
+            return;
+        }
+
+        let generations = self.history.entry(self.cells.fingerprint()).or_default();
+        // Hashes can collide, so a match is checked against the generation
+        // itself, worked out again from the start.
+        if !generations.is_empty() {
+            let mut replay = self.start.clone();
+            let mut replayed = 0;
+            for &start in generations.iter() {
+                for _ in replayed..start {
+                    replay = replay.advance(rule);
+                }
+                replayed = start;
+
+                if replay.same_state(&self.cells) {
+                    self.cycle = Some(Cycle {
+                        start,
+                        period: self.generation - start,
+                    });
+                    return;
+                }
+            }
+        }
+        generations.push(self.generation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    /// Conway's Game of Life, with 1 for a live cell.
+    struct Life;
+
+    impl Rule<u32> for Life {
+        fn next(&self, _phase: usize, view: &View<u32>) -> u32 {
+            match (view.cell(), view.neighbours8().sum::<u32>()) {
+                (1, 2) | (_, 3) => 1,
+                _ => 0,
+            }
+        }
+    }
+
+    fn grid(text: &str) -> Grid<u32> {
+        Grid::digits(parse::lines(text)).unwrap()
+    }
+
+    #[test]
+    fn a_block_is_a_fixed_point() {
+        let mut life = Automaton::new(grid("0000\n0110\n0110\n0000"), Boundary::Finite);
+
+        assert_eq!(life.run_until_stable(&Life), 1);
+        assert_eq!(
+            life.cycle(),
+            Some(Cycle {
+                start: 0,
+                period: 1
+            })
+        );
+    }
+
+    #[test]
+    fn a_blinker_cycles() {
+        let mut life = Automaton::new(grid("000\n111\n000"), Boundary::Finite);
+
+        life.step(&Life);
+        assert_eq!(life.grid(), &grid("010\n010\n010"));
+        assert_eq!(
+            life.run_until_cycle(&Life),
+            Cycle {
+                start: 0,
+                period: 2
+            }
+        );
+    }
+
+    #[test]
+    fn wrapping_cells_see_the_other_side() {
+        // On a 3x3 torus every cell neighbours every other one.
+        let mut life = Automaton::new(grid("000\n110\n001"), Boundary::Wrapping);
+
+        life.step(&Life);
+        assert_eq!(life.grid(), &grid("111\n111\n111"));
+    }
+
+    #[test]
+    fn an_infinite_grid_grows_and_its_background_evolves() {
+        struct Invert;
+
+        impl Rule<u32> for Invert {
+            fn next(&self, _phase: usize, view: &View<u32>) -> u32 {
+                1 - view.cell()
+            }
+        }
+
+        let mut automaton = Automaton::new(grid("1"), Boundary::Infinite(0));
+
+        automaton.step(&Invert);
+        assert_eq!(automaton.grid(), &grid("111\n101\n111"));
+        assert_eq!(automaton.background(), Some(&1));
+    }
+
+    #[test]
+    fn an_infinite_grid_settles_however_much_it_grows() {
+        let mut life = Automaton::new(grid("11\n11"), Boundary::Infinite(0));
+        assert_eq!(life.run_until_stable(&Life), 1);
+        assert_eq!(life.grid().width(), 4);
+
+        let mut life = Automaton::new(grid("111"), Boundary::Infinite(0));
+        assert_eq!(
+            life.run_until_cycle(&Life),
+            Cycle {
+                start: 0,
+                period: 2
+            }
+        );
+    }
+
+    #[test]
+    fn phases_that_undo_each_other_change_nothing() {
+        struct Flicker;
+
+        impl Rule<u32> for Flicker {
+            const PHASES: usize = 2;
+
+            fn next(&self, _phase: usize, view: &View<u32>) -> u32 {
+                1 - view.cell()
+            }
+        }
+
+        let mut automaton = Automaton::new(grid("10\n01"), Boundary::Finite);
+        assert!(!automaton.step(&Flicker));
+        assert_eq!(automaton.run_until_stable(&Flicker), 2);
+        assert_eq!(
+            automaton.cycle(),
+            Some(Cycle {
+                start: 0,
+                period: 1
+            })
+        );
+    }
+}
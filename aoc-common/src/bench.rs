@@ -0,0 +1,152 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{ParseError, Solution};
+
+/// How long to spend measuring each stage. Every stage is run for at least
+/// `warm_up` before any samples are kept, then sampled until `measurement`
+/// has passed, but never fewer than `min_samples` times.
+#[derive(Clone, Debug)]
+pub struct Budget {
+    pub warm_up: Duration,
+    pub measurement: Duration,
+    pub min_samples: usize,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(300),
+            measurement: Duration::from_secs(1),
+            min_samples: 5,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Estimate {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub samples: usize,
+}
+
+pub struct Row {
+    pub day: u8,
+    pub stage: Stage,
+    pub estimate: Estimate,
+}
+
+/// Times `f` within `budget`.
+pub fn measure<T>(budget: &Budget, mut f: impl FnMut() -> T) -> Estimate {
+    let start = Instant::now();
+    while start.elapsed() < budget.warm_up {
+        black_box(f());
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.len() < budget.min_samples || start.elapsed() < budget.measurement {
+        let sample = Instant::now();
+        black_box(f());
+        samples.push(sample.elapsed());
+    }
+    samples.sort();
+
+    Estimate {
+        median: samples[samples.len() / 2],
+        min: samples[0],
+        max: samples[samples.len() - 1],
+        samples: samples.len(),
+    }
+}
+
+/// Times parsing `input` and then solving each part from the parsed input,
+/// so that the two can be told apart.
+pub fn day<S: Solution>(day: u8, input: &str, budget: &Budget) -> Result<Vec<Row>, ParseError> {
+    let parsed = S::parse(input)?;
+
+    let mut rows = vec![Row {
+        day,
+        stage: Stage::Parse,
+        estimate: measure(budget, || S::parse(input)),
+    }];
+    rows.push(Row {
+        day,
+        stage: Stage::Part(1),
+        estimate: measure(budget, || S::part1(&parsed)),
+    });
+    if S::PARTS >= 2 {
+        rows.push(Row {
+            day,
+            stage: Stage::Part(2),
+            estimate: measure(budget, || S::part2(&parsed)),
+        });
+    }
+
+    Ok(rows)
+}
+
+/// Prints every measurement.
+pub fn table(rows: &[Row]) {
+    println!(
+        "Day  Stage   {:>12}  {:>12}  {:>12}  Samples",
+        "Median", "Min", "Max"
+    );
+    for row in rows {
+        println!(
+            "{:3}  {:6}  {:>12}  {:>12}  {:>12}  {:7}",
+            row.day,
+            row.stage.to_string(),
+            format!("{:.3?}", row.estimate.median),
+            format!("{:.3?}", row.estimate.min),
+            format!("{:.3?}", row.estimate.max),
+            row.estimate.samples
+        );
+    }
+}
+
+/// Prints each day's total median time, slowest first, along with its share
+/// of the time taken by every day together.
+pub fn summary(rows: &[Row]) {
+    let mut days: Vec<(u8, Duration)> = Vec::new();
+    for row in rows {
+        match days.last_mut() {
+            Some((day, total)) if *day == row.day => *total += row.estimate.median,
+            _ => days.push((row.day, row.estimate.median)),
+        }
+    }
+    days.sort_by_key(|&(_, total)| std::cmp::Reverse(total));
+
+    let total: Duration = days.iter().map(|&(_, total)| total).sum();
+    let mut cumulative = Duration::ZERO;
+
+    println!("Day  {:>12}  Share  Cumulative", "Total");
+    for (day, time) in days {
+        cumulative += time;
+        println!(
+            "{:3}  {:>12}  {:4.1}%  {:9.1}%",
+            day,
+            format!("{:.3?}", time),
+            100.0 * time.as_secs_f64() / total.as_secs_f64(),
+            100.0 * cumulative.as_secs_f64() / total.as_secs_f64()
+        );
+    }
+    println!("all  {:>12}", format!("{:.3?}", total));
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::path::Path;
+
+use crate::Solution;
+
+/// Checks a day's solution against the worked examples kept in its crate.
+///
+/// Every `test-input*` file in `directory` is an example. Its expected
+/// answers live alongside it in `<example>.part1` and `<example>.part2`, and
+/// parts without one of those are skipped, since the puzzle text doesn't
+/// always give an answer for both. Panics listing every mismatch, or if there
+/// turn out to be no answers to check at all.
+pub fn check<S: Solution>(directory: impl AsRef<Path>) {
+    let directory = directory.as_ref();
+    let mut examples: Vec<_> = fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension().is_none()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("test-input"))
+        })
+        .collect();
+    examples.sort();
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+
+    for example in examples {
+        let input = fs::read_to_string(&example).unwrap();
+
+        for part in 1..=S::PARTS {
+            let expected = match fs::read_to_string(example.with_extension(format!("part{}", part)))
+            {
+                Ok(expected) => expected,
+                Err(_) => continue,
+            };
+            checked += 1;
+
+            let name = example.file_name().unwrap().to_string_lossy();
+            match S::solve(&input, part) {
+                Ok(Some(answer)) if answer.to_string().trim_end() == expected.trim_end() => (),
+                Ok(Some(answer)) => failures.push(format!(
+                    "{} part {}: expected {}, got {}",
+                    name,
+                    part,
+                    expected.trim_end(),
+                    answer
+                )),
+                Ok(None) => failures.push(format!("{} part {}: no answer", name, part)),
+                Err(e) => {
+                    failures.push(format!("{} part {}: {}", name, part, e.with_file(&example)))
+                }
+            }
+        }
+    }
+
+    assert!(checked > 0, "no example answers in {}", directory.display());
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
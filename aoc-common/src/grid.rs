@@ -0,0 +1,305 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{InputLine, ParseError};
+
+/// Parses a block of lines into rows of cells, one per character. `cell`
+/// returns `None` for characters that aren't `expected`.
+pub fn cells<'a, T>(
+    lines: impl IntoIterator<Item = InputLine<'a>>,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    lines
+        .into_iter()
+        .map(|line| {
+            line.text
+                .chars()
+                .enumerate()
+                .map(|(index, c)| {
+                    cell(c).ok_or_else(|| ParseError::new(line.number, index + 1, expected))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// A rectangular grid of cells, addressed by `(x, y)` with `(0, 0)` at the
+/// top left. A wrapping grid is a torus: stepping off one edge comes back on
+/// at the other.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    wrapping: bool,
+}
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+            wrapping: false,
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for row in rows {
+            assert_eq!(*width.get_or_insert(row.len()), row.len(), "ragged grid");
+            cells.extend(row);
+            height += 1;
+        }
+
+        Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+            wrapping: false,
+        }
+    }
+
+    /// Parses a block of lines, one cell per character, like [`cells`] does,
+    /// but also insists that every line is the same length.
+    pub fn parse<'a>(
+        lines: impl IntoIterator<Item = InputLine<'a>>,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut rows = Vec::new();
+
+        for line in lines {
+            let row = cells([line], expected, &cell)?.remove(0);
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                return Err(line.error(line.text, format!("a row {} wide", width)));
+            }
+            rows.push(row);
+        }
+
+        Ok(Self::from_rows(rows))
+    }
+
+    /// Makes stepping off an edge of the grid wrap around to the other side.
+    pub fn wrapping(self) -> Self {
+        Self {
+            wrapping: true,
+            ..self
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// The position `(dx, dy)` away from `(x, y)`, or `None` if that's off
+    /// the edge of a grid that doesn't wrap.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x as isize + dx;
+        let y = y as isize + dy;
+
+        if self.wrapping {
+            if self.is_empty() {
+                return None;
+            }
+            Some((
+                x.rem_euclid(self.width as isize) as usize,
+                y.rem_euclid(self.height as isize) as usize,
+            ))
+        } else if (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y) {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    /// The positions directly above, left, right and below `position`.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    /// The positions around `position`, including the diagonals.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    /// Every position in the grid, a row at a time.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell in the grid, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            wrapping: self.wrapping,
+        }
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a block of lines like `2199943210` into a grid of digits.
+    pub fn digits<'a>(lines: impl IntoIterator<Item = InputLine<'a>>) -> Result<Self, ParseError> {
+        Self::parse(lines, "a digit", |c| c.to_digit(10))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn neighbours_stop_at_the_edges() {
+        let grid = Grid::digits(parse::lines("123\n456\n789")).unwrap();
+
+        let corner: Vec<u32> = grid.neighbours8((0, 0)).map(|p| grid[p]).collect();
+        assert_eq!(corner, [2, 4, 5]);
+        let middle: Vec<u32> = grid.neighbours4((1, 1)).map(|p| grid[p]).collect();
+        assert_eq!(middle, [2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn wrapping_neighbours_come_round_the_other_side() {
+        let grid = Grid::digits(parse::lines("123\n456\n789"))
+            .unwrap()
+            .wrapping();
+
+        let corner: Vec<u32> = grid.neighbours4((0, 0)).map(|p| grid[p]).collect();
+        assert_eq!(corner, [7, 3, 2, 4]);
+        assert_eq!(grid.neighbours8((2, 2)).count(), 8);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::digits(parse::lines("12\n34\n56")).unwrap();
+
+        assert_eq!(grid.row(1), [3, 4]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 4, 6]);
+        assert_eq!(grid.to_string(), "12\n34\n56\n");
+    }
+
+    #[test]
+    fn ragged_rows_are_an_error() {
+        let error = Grid::digits(parse::lines("123\n45\n")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a row 3 wide");
+    }
+}
@@ -0,0 +1,385 @@
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::grid::Grid;
+
+/// Colours for drawing a heatmap, blended evenly from the first (for the
+/// smallest value) to the last (for the largest).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Palette {
+    stops: Vec<[u8; 3]>,
+}
+
+impl Palette {
+    pub fn new(stops: Vec<[u8; 3]>) -> Self {
+        assert!(!stops.is_empty(), "a palette needs at least one colour");
+        Self { stops }
+    }
+
+    /// Black to white.
+    pub fn grey() -> Self {
+        Self::new(vec![[0, 0, 0], [255, 255, 255]])
+    }
+
+    /// Black through red and yellow to white.
+    pub fn heat() -> Self {
+        Self::new(vec![[0, 0, 0], [255, 0, 0], [255, 255, 0], [255, 255, 255]])
+    }
+
+    /// The colour for `t`, from 0 to 1.
+    pub fn colour(&self, t: f64) -> [u8; 3] {
+        let position = t.clamp(0.0, 1.0) * (self.stops.len() - 1) as f64;
+        let index = (position as usize).min(self.stops.len() - 1);
+        let (from, to) = (
+            self.stops[index],
+            self.stops[(index + 1).min(self.stops.len() - 1)],
+        );
+        let fraction = position - index as f64;
+
+        [0, 1, 2]
+            .map(|c| (from[c] as f64 + (to[c] as f64 - from[c] as f64) * fraction).round() as u8)
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::heat()
+    }
+}
+
+/// `grey`, `heat`, or colours like `#000080,#ffffff`.
+impl FromStr for Palette {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "grey" | "gray" => return Ok(Self::grey()),
+            "heat" => return Ok(Self::heat()),
+            _ => (),
+        }
+
+        let colour = |c: &str| {
+            let hex = c.strip_prefix('#').filter(|hex| hex.len() == 6);
+            let channel =
+                |i: usize| hex.and_then(|hex| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok());
+            match (channel(0), channel(2), channel(4)) {
+                (Some(r), Some(g), Some(b)) => Ok([r, g, b]),
+                _ => Err(format!("{:?} isn't a colour like #ff8000", c)),
+            }
+        };
+        Ok(Self::new(
+            s.split(',').map(colour).collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+/// The most pixels a heatmap will be drawn with, as an image has to have
+/// every one of them.
+const MAX_PIXELS: usize = 1 << 26;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Values {
+    Dense(Grid<f64>),
+    /// Just the cells there are, relative to the origin, with 0 everywhere
+    /// else in the box. The box can be 2^64 cells across, so its size
+    /// doesn't fit in a usize.
+    Sparse {
+        width: u128,
+        height: u128,
+        cells: Vec<((usize, usize), f64)>,
+    },
+}
+
+/// A grid of values to draw as an image or dump as text. Its top left cell
+/// is at `origin`, for things that don't start at (0, 0).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Heatmap {
+    origin: (i64, i64),
+    values: Values,
+}
+
+impl Heatmap {
+    pub fn from_grid<T>(grid: &Grid<T>, value: impl Fn(&T) -> f64) -> Self {
+        Self {
+            origin: (0, 0),
+            values: Values::Dense(grid.map(value)),
+        }
+    }
+
+    /// Builds a heatmap just big enough for `cells`, with 0 anywhere there
+    /// isn't one. Only the cells themselves are kept, so they can be
+    /// spread over any area.
+    pub fn from_cells(cells: impl IntoIterator<Item = ((i64, i64), f64)>) -> Self {
+        let mut cells: Vec<_> = cells.into_iter().collect();
+        if cells.is_empty() {
+            return Self::from_grid(&Grid::new(0, 0, 0.0), |&v| v);
+        }
+        cells.sort_by_key(|&((x, y), _)| (y, x));
+
+        let (xs, ys) = (cells.iter().map(|c| c.0 .0), cells.iter().map(|c| c.0 .1));
+        let origin = (xs.clone().min().unwrap(), ys.clone().min().unwrap());
+        // Any offset from the origin fits in a u64, but the box can be one
+        // wider than that.
+        let offset = |v: i64, min: i64| (v as i128 - min as i128) as u64 as usize;
+        let (width, height) = (
+            offset(xs.max().unwrap(), origin.0) as u128 + 1,
+            offset(ys.max().unwrap(), origin.1) as u128 + 1,
+        );
+
+        let cells = cells
+            .into_iter()
+            .map(|((x, y), value)| ((offset(x, origin.0), offset(y, origin.1)), value))
+            .collect();
+
+        Self {
+            origin,
+            values: Values::Sparse {
+                width,
+                height,
+                cells,
+            },
+        }
+    }
+
+    pub fn width(&self) -> u128 {
+        match &self.values {
+            Values::Dense(grid) => grid.width() as u128,
+            Values::Sparse { width, .. } => *width,
+        }
+    }
+
+    pub fn height(&self) -> u128 {
+        match &self.values {
+            Values::Dense(grid) => grid.height() as u128,
+            Values::Sparse { height, .. } => *height,
+        }
+    }
+
+    /// The coordinates of a cell, from where it is relative to the origin.
+    fn coordinates(&self, (x, y): (usize, usize)) -> (i64, i64) {
+        (
+            (self.origin.0 as i128 + x as i128) as i64,
+            (self.origin.1 as i128 + y as i128) as i64,
+        )
+    }
+
+    /// Every cell there is, relative to the origin, in reading order. For a
+    /// sparse heatmap, that doesn't include the zeroes in between.
+    fn cells(&self) -> Box<dyn Iterator<Item = ((usize, usize), f64)> + '_> {
+        match &self.values {
+            Values::Dense(grid) => Box::new(grid.positions().map(|p| (p, grid[p]))),
+            Values::Sparse { cells, .. } => Box::new(cells.iter().copied()),
+        }
+    }
+
+    /// An error if there are too many values to draw as an image.
+    fn check_size(&self) -> io::Result<()> {
+        match self.width().checked_mul(self.height()) {
+            Some(pixels) if pixels <= MAX_PIXELS as u128 => Ok(()),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "a {}x{} heatmap is too big to draw; try csv or json",
+                    self.width(),
+                    self.height()
+                ),
+            )),
+        }
+    }
+
+    /// Every value, a row at a time.
+    fn dense(&self) -> io::Result<Vec<f64>> {
+        self.check_size()?;
+        let (width, height) = (self.width() as usize, self.height() as usize);
+        Ok(match &self.values {
+            Values::Dense(grid) => grid.iter().copied().collect(),
+            Values::Sparse { cells, .. } => {
+                let mut values = vec![0.0; width * height];
+                for &((x, y), value) in cells {
+                    values[y * width + x] = value;
+                }
+                values
+            }
+        })
+    }
+
+    /// Every value scaled to between 0 and 1, a row at a time.
+    fn scaled(&self) -> io::Result<Vec<f64>> {
+        let mut values = self.dense()?;
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        for v in values.iter_mut() {
+            *v = if max > min {
+                (*v - min) / (max - min)
+            } else {
+                0.0
+            };
+        }
+        Ok(values)
+    }
+
+    /// Writes a greyscale binary PGM.
+    pub fn write_pgm(&self, mut out: impl Write) -> io::Result<()> {
+        let pixels: Vec<u8> = self
+            .scaled()?
+            .into_iter()
+            .map(|t| (t * 255.0).round() as u8)
+            .collect();
+        write!(out, "P5\n{} {}\n255\n", self.width(), self.height())?;
+        out.write_all(&pixels)
+    }
+
+    pub fn write_png(&self, out: impl Write, palette: &Palette) -> io::Result<()> {
+        let pixels: Vec<u8> = self
+            .scaled()?
+            .into_iter()
+            .flat_map(|t| palette.colour(t))
+            .collect();
+
+        let mut encoder = png::Encoder::new(out, self.width() as u32, self.height() as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&pixels))
+            .map_err(io::Error::other)
+    }
+
+    /// Writes every cell as a line of `x,y,value`. A sparse heatmap only
+    /// writes the cells it has.
+    pub fn write_csv(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "x,y,value")?;
+        for (position, value) in self.cells() {
+            let (x, y) = self.coordinates(position);
+            writeln!(out, "{},{},{}", x, y, value)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the origin, the size and the values: as rows for a dense
+    /// heatmap, or as `[x, y, value]` cells for a sparse one.
+    pub fn write_json(&self, out: impl Write) -> io::Result<()> {
+        #[derive(Serialize)]
+        struct Json<'a> {
+            origin: (i64, i64),
+            width: u128,
+            height: u128,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            rows: Option<Vec<&'a [f64]>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            cells: Option<Vec<(i64, i64, f64)>>,
+        }
+
+        let (rows, cells) = match &self.values {
+            Values::Dense(grid) => (Some(grid.rows().collect()), None),
+            Values::Sparse { .. } => {
+                let cells = self.cells().map(|(position, value)| {
+                    let (x, y) = self.coordinates(position);
+                    (x, y, value)
+                });
+                (None, Some(cells.collect()))
+            }
+        };
+        let json = Json {
+            origin: self.origin,
+            width: self.width(),
+            height: self.height(),
+            rows,
+            cells,
+        };
+        serde_json::to_writer(out, &json).map_err(io::Error::other)
+    }
+
+    /// Writes the heatmap to `path`, in the format its extension asks for:
+    /// `pgm`, `png`, `csv` or `json`.
+    pub fn save(&self, path: &Path, palette: &Palette) -> io::Result<()> {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        if !["pgm", "png", "csv", "json"].contains(&extension) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("can't write a heatmap as {:?}", extension),
+            ));
+        }
+
+        if ["pgm", "png"].contains(&extension) {
+            self.check_size()?;
+        }
+
+        let mut out = io::BufWriter::new(std::fs::File::create(path)?);
+        match extension {
+            "pgm" => self.write_pgm(&mut out)?,
+            "png" => self.write_png(&mut out, palette)?,
+            "csv" => self.write_csv(&mut out)?,
+            _ => self.write_json(&mut out)?,
+        }
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palettes_blend_between_stops() {
+        let palette: Palette = "#000000,#ff0000,#ffffff".parse().unwrap();
+
+        assert_eq!(palette.colour(0.0), [0, 0, 0]);
+        assert_eq!(palette.colour(0.25), [128, 0, 0]);
+        assert_eq!(palette.colour(0.5), [255, 0, 0]);
+        assert_eq!(palette.colour(1.0), [255, 255, 255]);
+        assert!("#ff00".parse::<Palette>().is_err());
+    }
+
+    #[test]
+    fn sparse_cells_keep_their_coordinates() {
+        let heatmap = Heatmap::from_cells([((-1, 5), 2.0), ((1, 6), 4.0)]);
+        assert_eq!((heatmap.width(), heatmap.height()), (3, 2));
+
+        let mut csv = Vec::new();
+        heatmap.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv, "x,y,value\n-1,5,2\n1,6,4\n");
+
+        let mut pgm = Vec::new();
+        heatmap.write_pgm(&mut pgm).unwrap();
+        assert_eq!(pgm[pgm.len() - 6..], [128, 0, 0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn far_apart_cells_are_too_big_to_draw() {
+        let far = 1 << 40;
+        let heatmap = Heatmap::from_cells([((-far, -far), 1.0), ((far, far), 2.0)]);
+        assert_eq!(heatmap.width(), (2 << 40) + 1);
+
+        let mut csv = Vec::new();
+        heatmap.write_csv(&mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap().lines().count(), 3);
+
+        let e = heatmap
+            .write_png(Vec::new(), &Palette::default())
+            .unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn cells_can_be_as_far_apart_as_they_get() {
+        let heatmap = Heatmap::from_cells([((i64::MIN, 0), 1.0), ((i64::MAX, 0), 2.0)]);
+        assert_eq!((heatmap.width(), heatmap.height()), (1 << 64, 1));
+        assert!(heatmap.write_pgm(Vec::new()).is_err());
+
+        let mut csv = Vec::new();
+        heatmap.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().last(), Some(&*format!("{},0,2", i64::MAX)));
+
+        let mut json = Vec::new();
+        heatmap.write_json(&mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.contains(r#""width":18446744073709551616,"height":1"#));
+    }
+}
@@ -0,0 +1,26 @@
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+/// Reads the puzzle input from `path`, or from stdin if no path was given or
+/// the path is `-`.
+pub fn read(path: Option<&Path>) -> std::io::Result<String> {
+    match path {
+        Some(path) if path != Path::new("-") => std::fs::read_to_string(path),
+        _ => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+/// Opens the puzzle input like [`read`] does, but for reading a bit at a
+/// time, for inputs too big to hold all at once.
+pub fn open(path: Option<&Path>) -> std::io::Result<Box<dyn BufRead>> {
+    match path {
+        Some(path) if path != Path::new("-") => {
+            Ok(Box::new(BufReader::new(std::fs::File::open(path)?)))
+        }
+        _ => Ok(Box::new(std::io::stdin().lock())),
+    }
+}
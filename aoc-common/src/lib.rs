@@ -0,0 +1,14 @@
+pub mod automaton;
+pub mod bench;
+pub mod examples;
+pub mod grid;
+pub mod heatmap;
+pub mod input;
+pub mod parse;
+pub mod render;
+pub mod report;
+pub mod search;
+pub mod solution;
+
+pub use parse::ParseError;
+pub use solution::{Answer, Solution};
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Something in the puzzle input wasn't what we expected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at `line` and `column`, both counting from 1.
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// An error just past the last line of `input`, for when it ends early.
+    pub fn end(input: &str, expected: impl Into<String>) -> Self {
+        Self::new(input.lines().count() + 1, 1, expected)
+    }
+
+    pub fn with_file(self, file: impl AsRef<Path>) -> Self {
+        Self {
+            file: Some(file.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let file = self
+            .file
+            .as_deref()
+            .map_or("<input>".into(), Path::to_string_lossy);
+        write!(
+            f,
+            "{}:{}:{}: expected {}",
+            file, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of puzzle input that knows where it came from, so that anything
+/// parsed out of it can be reported against the right line and column.
+#[derive(Clone, Copy, Debug)]
+pub struct InputLine<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    /// An error pointing at `part`, which must be a slice of this line.
+    pub fn error(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column(part), expected)
+    }
+
+    /// Parses `part` (a slice of this line) with its `FromStr` implementation.
+    pub fn field<T: FromStr>(&self, part: &str, expected: &str) -> Result<T, ParseError> {
+        part.parse().map_err(|_| self.error(part, expected))
+    }
+
+    /// Parses `part` (a slice of this line) with a `FromStr` implementation
+    /// that reports its own errors, moving them to where `part` is.
+    pub fn parse<T: FromStr<Err = ParseError>>(&self, part: &str) -> Result<T, ParseError> {
+        part.parse().map_err(|e: ParseError| ParseError {
+            line: self.number,
+            column: self.column(part) + e.column - 1,
+            ..e
+        })
+    }
+
+    /// Splits the line on `separator`, expecting exactly `N` pieces.
+    pub fn split<const N: usize>(
+        &self,
+        separator: &str,
+        expected: &str,
+    ) -> Result<[&'a str; N], ParseError> {
+        let pieces: Vec<&'a str> = self.text.split(separator).collect();
+        pieces
+            .try_into()
+            .map_err(|_| ParseError::new(self.number, 1, expected))
+    }
+
+    fn column(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset <= self.text.len() {
+            offset + 1
+        } else {
+            1
+        }
+    }
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input.lines().enumerate().map(|(index, text)| InputLine {
+        number: index + 1,
+        text,
+    })
+}
@@ -0,0 +1,20 @@
+/// Something that can show a puzzle being solved, one state at a time.
+///
+/// Solutions that can be watched take a renderer and hand it their state as
+/// they go. Every method does nothing by default, so [`Headless`] is all a
+/// solution needs to run quietly in a pipe, a test or a benchmark.
+pub trait Renderer<T: ?Sized> {
+    /// Called once with the starting state.
+    fn begin(&mut self, _state: &T) {}
+
+    /// Called every time the state changes.
+    fn frame(&mut self, _state: &T) {}
+
+    /// Called once with the final state.
+    fn finish(&mut self, _state: &T) {}
+}
+
+/// A renderer that doesn't draw anything.
+pub struct Headless;
+
+impl<T: ?Sized> Renderer<T> for Headless {}
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use crate::Answer;
+
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// Prints a table of answers and how long each took. Answers spanning several
+/// lines (like day 13's folded paper) are printed below their row.
+pub fn table(rows: &[Row]) {
+    let answers: Vec<String> = rows.iter().map(|row| row.answer.to_string()).collect();
+
+    let width = answers
+        .iter()
+        .filter(|answer| !answer.contains('\n'))
+        .map(|answer| answer.len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap();
+
+    println!("Day  Part  {:width$}  {:>12}", "Answer", "Time");
+    for (row, answer) in rows.iter().zip(&answers) {
+        let (answer, block) = if answer.contains('\n') {
+            ("", Some(answer))
+        } else {
+            (answer.as_str(), None)
+        };
+
+        println!(
+            "{:3}  {:4}  {:width$}  {:>12}",
+            row.day,
+            row.part,
+            answer,
+            format!("{:.3?}", row.time)
+        );
+
+        if let Some(block) = block {
+            for line in block.lines() {
+                println!("          {}", line);
+            }
+        }
+    }
+}
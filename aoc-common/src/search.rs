@@ -0,0 +1,196 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use priority_queue::PriorityQueue;
+
+use crate::render::Renderer;
+
+/// How much work a search has done so far.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States taken off the open set and expanded.
+    pub expanded: usize,
+    /// States waiting in the open set.
+    pub open: usize,
+    /// States reached by any route.
+    pub seen: usize,
+}
+
+/// The cheapest way to a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found<S> {
+    pub cost: usize,
+    /// Every state from the start to the goal, inclusive.
+    pub path: Vec<S>,
+    pub stats: Stats,
+}
+
+/// A search in progress, as handed to a [`Renderer`]: the state being
+/// expanded, the cheapest cost of reaching it and the work done so far.
+pub struct Progress<'a, S> {
+    pub current: &'a S,
+    pub cost: usize,
+    pub stats: Stats,
+    came_from: &'a HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash> Progress<'_, S> {
+    /// The cheapest known path from the start to the current state.
+    pub fn path(&self) -> Vec<S> {
+        path_to(self.came_from, self.current)
+    }
+}
+
+fn path_to<S: Clone + Eq + Hash>(came_from: &HashMap<S, S>, to: &S) -> Vec<S> {
+    let mut path = vec![to.clone()];
+    while let Some(from) = came_from.get(path.last().unwrap()) {
+        path.push(from.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Finds the cheapest path from `start` to a state satisfying `is_goal`,
+/// where `successors` gives the states reachable from a state and what each
+/// costs to reach.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+    renderer: &mut impl for<'a> Renderer<Progress<'a, S>>,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal, renderer)
+}
+
+/// Like [`dijkstra`], but guided by `heuristic`, an estimate of the cost
+/// from a state to the nearest goal. The path found is only the cheapest if
+/// the heuristic never overestimates.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+    renderer: &mut impl for<'a> Renderer<Progress<'a, S>>,
+) -> Option<Found<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut open = PriorityQueue::new();
+    let mut g = HashMap::from([(start.clone(), 0)]);
+    let mut came_from = HashMap::new();
+    let mut stats = Stats {
+        open: 1,
+        seen: 1,
+        ..Default::default()
+    };
+
+    open.push(start.clone(), Reverse(heuristic(&start)));
+    renderer.begin(&Progress {
+        current: &start,
+        cost: 0,
+        stats,
+        came_from: &came_from,
+    });
+
+    while let Some((current, _)) = open.pop() {
+        let cost = g[&current];
+        stats.open = open.len();
+
+        if is_goal(&current) {
+            renderer.finish(&Progress {
+                current: &current,
+                cost,
+                stats,
+                came_from: &came_from,
+            });
+            return Some(Found {
+                cost,
+                path: path_to(&came_from, &current),
+                stats,
+            });
+        }
+
+        for (next, step) in successors(&current) {
+            let tentative = cost + step;
+            if g.get(&next).is_some_and(|&known| known <= tentative) {
+                continue;
+            }
+
+            let estimate = tentative + heuristic(&next);
+            g.insert(next.clone(), tentative);
+            came_from.insert(next.clone(), current.clone());
+            open.push(next, Reverse(estimate));
+        }
+
+        stats.expanded += 1;
+        stats.open = open.len();
+        stats.seen = g.len();
+        renderer.frame(&Progress {
+            current: &current,
+            cost,
+            stats,
+            came_from: &came_from,
+        });
+    }
+
+    renderer.finish(&Progress {
+        current: &start,
+        cost: 0,
+        stats,
+        came_from: &came_from,
+    });
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::Headless;
+
+    /// Counting up by adding 1, which costs 1, or by doubling, which costs 3.
+    fn successors(&n: &u32) -> Vec<(u32, usize)> {
+        vec![(n + 1, 1), (n * 2, 3)]
+    }
+
+    #[test]
+    fn finds_the_cheapest_path() {
+        let found = dijkstra(1, successors, |&n| n == 10, &mut Headless).unwrap();
+
+        assert_eq!(found.cost, 7);
+        assert_eq!(found.path, [1, 2, 3, 4, 5, 10]);
+        assert!(found.stats.expanded > 0);
+    }
+
+    #[test]
+    fn a_heuristic_expands_fewer_states() {
+        let plain = dijkstra(1, successors, |&n| n == 10, &mut Headless).unwrap();
+        let guided = astar(
+            1,
+            successors,
+            |&n| if n > 10 { 100 } else { 0 },
+            |&n| n == 10,
+            &mut Headless,
+        )
+        .unwrap();
+
+        assert_eq!(guided.cost, plain.cost);
+        assert!(guided.stats.expanded <= plain.stats.expanded);
+    }
+
+    #[test]
+    fn unreachable_goals_are_not_found() {
+        let found = dijkstra(
+            1,
+            |&n: &u32| (n < 5).then_some((n + 1, 1)),
+            |&n| n == 10,
+            &mut Headless,
+        );
+        assert_eq!(found, None);
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::heatmap::Heatmap;
+use crate::ParseError;
+
+/// A puzzle answer. Most are numbers, but some (like day 13's folded paper)
+/// are text.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{}", number),
+            Self::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! number_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+number_answer!(u32, u64, usize, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// A day's puzzle: the input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+
+    /// How many parts the puzzle has. Day 25 only has one.
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(_input: &Self::Input) -> Answer {
+        panic!("this puzzle only has {} part(s)", Self::PARTS);
+    }
+
+    /// Parses `input` and solves `part`, or returns `None` if there's no such part.
+    fn solve(input: &str, part: u8) -> Result<Option<Answer>, ParseError> {
+        Ok(match part {
+            1 => Some(Self::part1(&Self::parse(input)?)),
+            2 if Self::PARTS >= 2 => Some(Self::part2(&Self::parse(input)?)),
+            _ => None,
+        })
+    }
+
+    /// Like `solve`, but draws the puzzle in the terminal as it's solved.
+    /// Only a few days know how; the rest just solve it.
+    fn visualise(input: &str, part: u8) -> Result<Option<Answer>, ParseError> {
+        Self::solve(input, part)
+    }
+
+    /// Parses `input` and draws a heatmap of `part`, for the days that have
+    /// something worth looking at.
+    fn heatmap(_input: &str, _part: u8) -> Result<Option<Heatmap>, ParseError> {
+        Ok(None)
+    }
+}
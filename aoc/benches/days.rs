@@ -0,0 +1,42 @@
+//! Times every day against its puzzle input, parsing and solving separately.
+//!
+//! Run with `cargo bench --bench days [-- directory]`, where `directory`
+//! holds the `dayNN/input` files (the workspace root by default). Days
+//! without an input are skipped.
+
+use std::path::PathBuf;
+
+use aoc_common::bench::{self, Budget};
+use aoc_common::input;
+
+fn main() {
+    // cargo passes `--bench` along, so only look at the other arguments.
+    let directory = std::env::args()
+        .skip(1)
+        .find(|arg| !arg.starts_with('-'))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".."));
+    let budget = Budget::default();
+    let mut rows = Vec::new();
+
+    for day in 1..=25 {
+        let path = directory.join(format!("day{:02}", day)).join("input");
+        let input = match input::read(Some(&path)) {
+            Ok(input) => input,
+            Err(_) => {
+                eprintln!("skipping day {}: no input at {}", day, path.display());
+                continue;
+            }
+        };
+
+        eprintln!("benchmarking day {}", day);
+        match aoc::bencher(day).unwrap()(day, &input, &budget) {
+            Ok(day_rows) => rows.extend(day_rows),
+            Err(e) => eprintln!("skipping day {}: {}", day, e.with_file(&path)),
+        }
+    }
+
+    bench::table(&rows);
+    println!();
+    bench::summary(&rows);
+}
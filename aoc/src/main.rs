@@ -0,0 +1,239 @@
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::Instant;
+
+use aoc::{heatmapper, solver, visualiser};
+use aoc_common::heatmap::Palette;
+use aoc_common::{input, report, ParseError};
+
+fn run(day: u8, part: u8, input: &str, visualise: bool) -> Result<Option<report::Row>, ParseError> {
+    let solver = if visualise {
+        visualiser(day)
+    } else {
+        solver(day)
+    };
+    let solver = match solver {
+        Some(solver) => solver,
+        None => return Ok(None),
+    };
+
+    let start = Instant::now();
+    let answer = solver(input, part)?;
+    let time = start.elapsed();
+
+    Ok(answer.map(|answer| report::Row {
+        day,
+        part,
+        answer,
+        time,
+    }))
+}
+
+/// Streams depths through the day 1 analyser, for depth logs of any length.
+fn sonar(args: &[String]) {
+    let mut window = 1;
+    let mut aggregation = day01::Aggregation::Sum;
+    let mut path = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--window" => match args.next().map(|n| n.parse()) {
+                Some(Ok(n)) if n > 0 => window = n,
+                _ => usage(),
+            },
+            "--aggregate" => match args.next().map(|a| a.parse()) {
+                Some(Ok(a)) => aggregation = a,
+                _ => usage(),
+            },
+            _ if path.is_none() => path = Some(Path::new(arg)),
+            _ => usage(),
+        }
+    }
+
+    let reader = input::open(path).unwrap_or_else(|e| {
+        eprintln!("couldn't read input: {}", e);
+        exit(1);
+    });
+    match day01::analyse(reader, window, aggregation) {
+        Ok(report) => println!("{}", report),
+        Err(day01::StreamError::Parse(e)) => {
+            let file = path.filter(|&p| p != Path::new("-"));
+            eprintln!("{}", e.with_file(file.unwrap_or(Path::new("<stdin>"))));
+            exit(1);
+        }
+        Err(e) => {
+            eprintln!("couldn't read input: {}", e);
+            exit(1);
+        }
+    }
+}
+
+/// Draws a heatmap of a day's puzzle into `output`.
+fn heatmap(args: &[String]) {
+    let mut palette = Palette::default();
+    let mut positional = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--palette" => match args.next().map(|p| p.parse()) {
+                Some(Ok(p)) => palette = p,
+                Some(Err(e)) => {
+                    eprintln!("{}", e);
+                    exit(2);
+                }
+                None => usage(),
+            },
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    let (day, part, output, path) = match positional[..] {
+        [day, part, output] => (day, part, output, None),
+        [day, part, output, path] => (day, part, output, Some(Path::new(path))),
+        _ => usage(),
+    };
+    let (day, part) = match (day.parse::<u8>(), part.parse::<u8>()) {
+        (Ok(day), Ok(part)) => (day, part),
+        _ => usage(),
+    };
+
+    let input = input::read(path).unwrap_or_else(|e| {
+        eprintln!("couldn't read input: {}", e);
+        exit(1);
+    });
+    let heatmap = match heatmapper(day).map(|mapper| mapper(&input, part)) {
+        Some(Ok(Some(heatmap))) => heatmap,
+        Some(Err(e)) => {
+            let file = path.filter(|&p| p != Path::new("-"));
+            eprintln!("{}", e.with_file(file.unwrap_or(Path::new("<stdin>"))));
+            exit(1);
+        }
+        _ => {
+            eprintln!("no heatmap for day {} part {}", day, part);
+            exit(1);
+        }
+    };
+
+    if let Err(e) = heatmap.save(Path::new(output), &palette) {
+        eprintln!("couldn't write {}: {}", output, e);
+        exit(1);
+    }
+}
+
+/// Prints how each day 8 display is wired up, to audit the ones that
+/// don't decode.
+fn wiring(args: &[String]) {
+    let mut glyphs = day08::Glyphs::digits();
+    let mut json = false;
+    let mut path = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--glyphs" => match args.next().map(String::as_str) {
+                Some("digits") => glyphs = day08::Glyphs::digits(),
+                Some("hex") => glyphs = day08::Glyphs::hexadecimal(),
+                _ => usage(),
+            },
+            _ if path.is_none() => path = Some(Path::new(arg)),
+            _ => usage(),
+        }
+    }
+
+    let input = input::read(path).unwrap_or_else(|e| {
+        eprintln!("couldn't read input: {}", e);
+        exit(1);
+    });
+    match day08::diagnose(&input, &glyphs) {
+        Ok(diagnoses) if json => println!("{}", day08::json(&diagnoses)),
+        Ok(diagnoses) => print!("{}", day08::table(&diagnoses)),
+        Err(e) => {
+            let file = path.filter(|&p| p != Path::new("-"));
+            eprintln!("{}", e.with_file(file.unwrap_or(Path::new("<stdin>"))));
+            exit(1);
+        }
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: aoc run [--visualise] <day> <part> [input]");
+    eprintln!("       aoc all [--visualise] [directory]");
+    eprintln!("       aoc heatmap [--palette <palette>] <day> <part> <output> [input]");
+    eprintln!("       aoc sonar [--window <n>] [--aggregate sum|mean|max] [input]");
+    eprintln!("       aoc wiring [--json] [--glyphs digits|hex] [input]");
+    exit(2);
+}
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("sonar") => return sonar(&args[1..]),
+        Some("heatmap") => return heatmap(&args[1..]),
+        Some("wiring") => return wiring(&args[1..]),
+        _ => (),
+    }
+
+    let visualise = args.iter().any(|arg| arg == "--visualise");
+    args.retain(|arg| arg != "--visualise");
+
+    let rows = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["run", day, part] | ["run", day, part, _] => {
+            let (day, part) = match (day.parse::<u8>(), part.parse::<u8>()) {
+                (Ok(day), Ok(part)) => (day, part),
+                _ => usage(),
+            };
+
+            let path = args.get(3).map(Path::new);
+            let input = input::read(path).unwrap_or_else(|e| {
+                eprintln!("couldn't read input: {}", e);
+                exit(1);
+            });
+
+            match run(day, part, &input, visualise) {
+                Ok(Some(row)) => vec![row],
+                Ok(None) => {
+                    eprintln!("no solution for day {} part {}", day, part);
+                    exit(1);
+                }
+                Err(e) => {
+                    let file = path.filter(|&p| p != Path::new("-"));
+                    eprintln!("{}", e.with_file(file.unwrap_or(Path::new("<stdin>"))));
+                    exit(1);
+                }
+            }
+        }
+        ["all"] | ["all", _] => {
+            let directory = args.get(1).map(PathBuf::from).unwrap_or_default();
+            let mut rows = Vec::new();
+
+            for day in 1..=25 {
+                let path = directory.join(format!("day{:02}", day)).join("input");
+                let input = match input::read(Some(&path)) {
+                    Ok(input) => input,
+                    Err(_) => {
+                        eprintln!("skipping day {}: no input at {}", day, path.display());
+                        continue;
+                    }
+                };
+
+                for part in 1..=2 {
+                    match run(day, part, &input, visualise) {
+                        Ok(row) => rows.extend(row),
+                        Err(e) => {
+                            eprintln!("skipping day {}: {}", day, e.with_file(&path));
+                            break;
+                        }
+                    }
+                }
+            }
+
+            rows
+        }
+        _ => usage(),
+    };
+
+    report::table(&rows);
+}
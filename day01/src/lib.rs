@@ -0,0 +1,219 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use aoc_common::parse::InputLine;
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+/// How the depths in a window are combined into one value to compare.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregation {
+    Sum,
+    Mean,
+    Max,
+}
+
+impl FromStr for Aggregation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sum" => Ok(Self::Sum),
+            "mean" => Ok(Self::Mean),
+            "max" => Ok(Self::Max),
+            _ => Err(format!("unknown aggregation {:?}", s)),
+        }
+    }
+}
+
+/// How a run of windows compared with each other.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub windows: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    /// The most increases in a row.
+    pub longest_increasing: usize,
+    /// The most decreases in a row.
+    pub longest_decreasing: usize,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "windows:            {}", self.windows)?;
+        writeln!(f, "increases:          {}", self.increases)?;
+        writeln!(f, "decreases:          {}", self.decreases)?;
+        writeln!(f, "plateaus:           {}", self.plateaus)?;
+        writeln!(f, "longest increasing: {}", self.longest_increasing)?;
+        write!(f, "longest decreasing: {}", self.longest_decreasing)
+    }
+}
+
+/// Compares each window of depths with the one before it as the depths go
+/// by, keeping only as many depths as fit in a window.
+pub struct Analyser {
+    window: usize,
+    aggregation: Aggregation,
+    depths: VecDeque<u64>,
+    sum: u64,
+    /// The depths that could still be the largest in the window, largest
+    /// first.
+    maxima: VecDeque<u64>,
+    previous: Option<u64>,
+    run: (std::cmp::Ordering, usize),
+    report: Report,
+}
+
+impl Analyser {
+    pub fn new(window: usize, aggregation: Aggregation) -> Self {
+        assert!(window > 0, "windows must hold at least one depth");
+
+        Self {
+            window,
+            aggregation,
+            depths: VecDeque::with_capacity(window + 1),
+            sum: 0,
+            maxima: VecDeque::new(),
+            previous: None,
+            run: (std::cmp::Ordering::Equal, 0),
+            report: Report::default(),
+        }
+    }
+
+    pub fn push(&mut self, depth: u64) {
+        self.depths.push_back(depth);
+        self.sum += depth;
+        while self.maxima.back().is_some_and(|&m| m < depth) {
+            self.maxima.pop_back();
+        }
+        self.maxima.push_back(depth);
+
+        if self.depths.len() > self.window {
+            let gone = self.depths.pop_front().unwrap();
+            self.sum -= gone;
+            if self.maxima.front() == Some(&gone) {
+                self.maxima.pop_front();
+            }
+        }
+
+        if self.depths.len() == self.window {
+            self.compare();
+        }
+    }
+
+    pub fn report(&self) -> &Report {
+        &self.report
+    }
+
+    /// The value of the current window. Every window is the same size, so
+    /// comparing means is the same as comparing sums.
+    fn value(&self) -> u64 {
+        match self.aggregation {
+            Aggregation::Sum | Aggregation::Mean => self.sum,
+            Aggregation::Max => self.maxima[0],
+        }
+    }
+
+    fn compare(&mut self) {
+        use std::cmp::Ordering;
+
+        let value = self.value();
+        self.report.windows += 1;
+
+        if let Some(previous) = self.previous.replace(value) {
+            let ordering = value.cmp(&previous);
+            self.run = match self.run {
+                (trend, length) if trend == ordering => (ordering, length + 1),
+                _ => (ordering, 1),
+            };
+
+            match ordering {
+                Ordering::Greater => {
+                    self.report.increases += 1;
+                    self.report.longest_increasing = self.report.longest_increasing.max(self.run.1);
+                }
+                Ordering::Less => {
+                    self.report.decreases += 1;
+                    self.report.longest_decreasing = self.report.longest_decreasing.max(self.run.1);
+                }
+                Ordering::Equal => self.report.plateaus += 1,
+            }
+        }
+    }
+}
+
+/// Something went wrong reading depths from a stream.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(std::io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+/// Analyses the depths read from `reader`, one per line, a line at a time.
+pub fn analyse(
+    mut reader: impl BufRead,
+    window: usize,
+    aggregation: Aggregation,
+) -> Result<Report, StreamError> {
+    let mut analyser = Analyser::new(window, aggregation);
+    let mut text = String::new();
+    let mut number = 0;
+
+    loop {
+        text.clear();
+        if reader.read_line(&mut text).map_err(StreamError::Io)? == 0 {
+            return Ok(analyser.report);
+        }
+        number += 1;
+
+        let line = InputLine {
+            number,
+            text: text.trim_end_matches(['\n', '\r']),
+        };
+        let depth = line
+            .field(line.text, "a depth")
+            .map_err(StreamError::Parse)?;
+        analyser.push(depth);
+    }
+}
+
+fn count_increases(depths: &[u64], window: usize) -> usize {
+    let mut analyser = Analyser::new(window, Aggregation::Sum);
+    for &depth in depths {
+        analyser.push(depth);
+    }
+    analyser.report().increases
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|line| line.field(line.text, "a depth"))
+            .collect()
+    }
+
+    fn part1(depths: &Self::Input) -> Answer {
+        count_increases(depths, 1).into()
+    }
+
+    fn part2(depths: &Self::Input) -> Answer {
+        count_increases(depths, 3).into()
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day01::Day01>(env!("CARGO_MANIFEST_DIR"));
+}
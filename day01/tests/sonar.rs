@@ -0,0 +1,38 @@
+use day01::{analyse, Aggregation, Report};
+
+const DEPTHS: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+#[test]
+fn streams_and_reports_every_trend() {
+    let report = analyse(DEPTHS.as_bytes(), 1, Aggregation::Sum).unwrap();
+
+    assert_eq!(
+        report,
+        Report {
+            windows: 10,
+            increases: 7,
+            decreases: 2,
+            plateaus: 0,
+            longest_increasing: 3,
+            longest_decreasing: 1,
+        }
+    );
+}
+
+#[test]
+fn windows_can_be_aggregated_differently() {
+    let sum = analyse(DEPTHS.as_bytes(), 3, Aggregation::Sum).unwrap();
+    let mean = analyse(DEPTHS.as_bytes(), 3, Aggregation::Mean).unwrap();
+    let max = analyse(DEPTHS.as_bytes(), 3, Aggregation::Max).unwrap();
+
+    assert_eq!(sum.increases, 5);
+    assert_eq!(mean, sum);
+    // 208 210 210 210 240 269 269 269
+    assert_eq!((max.increases, max.plateaus), (3, 4));
+}
+
+#[test]
+fn bad_depths_are_reported_with_their_line() {
+    let error = analyse("1\n2\nthree\n".as_bytes(), 1, Aggregation::Sum).unwrap_err();
+    assert_eq!(error.to_string(), "<input>:3:1: expected a depth");
+}
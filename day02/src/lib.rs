@@ -0,0 +1,156 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc_common::parse::InputLine;
+use aoc_common::render::{Headless, Renderer};
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Forward(i64),
+    Up(i64),
+    Down(i64),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = InputLine { number: 1, text: s };
+        let [direction, distance] = line.split(" ", "a direction and a distance")?;
+        let distance = line.field(distance, "a distance")?;
+
+        match direction {
+            "forward" => Ok(Self::Forward(distance)),
+            "up" => Ok(Self::Up(distance)),
+            "down" => Ok(Self::Down(distance)),
+            _ => Err(line.error(direction, "forward, up or down")),
+        }
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Forward(distance) => write!(f, "forward {}", distance),
+            Self::Up(distance) => write!(f, "up {}", distance),
+            Self::Down(distance) => write!(f, "down {}", distance),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Submarine {
+    pub position: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+/// How a submarine responds to commands.
+pub trait MotionModel {
+    fn apply(&self, submarine: &mut Submarine, command: Command);
+}
+
+/// Up and down change the depth directly.
+pub struct Simple;
+
+impl MotionModel for Simple {
+    fn apply(&self, submarine: &mut Submarine, command: Command) {
+        match command {
+            Command::Forward(distance) => submarine.position += distance,
+            Command::Up(distance) => submarine.depth -= distance,
+            Command::Down(distance) => submarine.depth += distance,
+        }
+    }
+}
+
+/// Up and down change the aim, and going forward dives along it.
+pub struct Aim;
+
+impl MotionModel for Aim {
+    fn apply(&self, submarine: &mut Submarine, command: Command) {
+        match command {
+            Command::Forward(distance) => {
+                submarine.position += distance;
+                submarine.depth += submarine.aim * distance;
+            }
+            Command::Up(distance) => submarine.aim -= distance,
+            Command::Down(distance) => submarine.aim += distance,
+        }
+    }
+}
+
+/// A command and where it left the submarine.
+pub struct Step {
+    pub number: usize,
+    pub command: Command,
+    pub submarine: Submarine,
+}
+
+/// Prints every step as it happens.
+struct Trace;
+
+impl Renderer<Step> for Trace {
+    fn frame(&mut self, step: &Step) {
+        println!(
+            "{:5}  {:12}  position {:6}  depth {:9}  aim {:6}",
+            step.number,
+            step.command.to_string(),
+            step.submarine.position,
+            step.submarine.depth,
+            step.submarine.aim
+        );
+    }
+}
+
+pub fn pilot(
+    commands: &[Command],
+    model: &impl MotionModel,
+    trace: &mut impl Renderer<Step>,
+) -> Submarine {
+    let mut submarine = Submarine::default();
+
+    for (index, &command) in commands.iter().enumerate() {
+        model.apply(&mut submarine, command);
+        trace.frame(&Step {
+            number: index + 1,
+            command,
+            submarine,
+        });
+    }
+
+    submarine
+}
+
+fn answer(submarine: Submarine) -> Answer {
+    (submarine.position * submarine.depth).into()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|line| line.parse(line.text))
+            .collect()
+    }
+
+    fn part1(commands: &Self::Input) -> Answer {
+        answer(pilot(commands, &Simple, &mut Headless))
+    }
+
+    fn part2(commands: &Self::Input) -> Answer {
+        answer(pilot(commands, &Aim, &mut Headless))
+    }
+
+    fn visualise(input: &str, part: u8) -> Result<Option<Answer>, ParseError> {
+        let commands = Self::parse(input)?;
+        Ok(match part {
+            1 => Some(answer(pilot(&commands, &Simple, &mut Trace))),
+            2 => Some(answer(pilot(&commands, &Aim, &mut Trace))),
+            _ => None,
+        })
+    }
+}
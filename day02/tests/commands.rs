@@ -0,0 +1,11 @@
+use aoc_common::Solution;
+use day02::Day02;
+
+#[test]
+fn unknown_commands_are_reported_with_their_line() {
+    let error = Day02::parse("forward 5\ndown 3\nsideways 2\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "<input>:3:1: expected forward, up or down"
+    );
+}
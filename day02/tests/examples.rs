@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day02::Day02>(env!("CARGO_MANIFEST_DIR"));
+}
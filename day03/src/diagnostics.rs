@@ -0,0 +1,252 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc_common::parse;
+use aoc_common::ParseError;
+
+/// One line of the diagnostic report: a binary number of any width, packed
+/// 64 bits to a word. Column 0 is the leftmost, most significant bit.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Report {
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl Report {
+    fn zero(width: usize) -> Self {
+        Self {
+            width,
+            words: vec![0; width.div_ceil(64)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn bit(&self, column: usize) -> bool {
+        assert!(column < self.width, "column {} out of range", column);
+        self.words[column / 64] & 1 << (column % 64) != 0
+    }
+
+    fn set(&mut self, column: usize, bit: bool) {
+        if bit {
+            self.words[column / 64] |= 1 << (column % 64);
+        } else {
+            self.words[column / 64] &= !(1 << (column % 64));
+        }
+    }
+
+    /// The columns with a 1 in them, from left to right.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    index * 64 + bit
+                })
+            })
+        })
+    }
+
+    /// The report as a number, if it's narrow enough to be one.
+    pub fn value(&self) -> Option<u128> {
+        (self.width <= 128)
+            .then(|| (0..self.width).fold(0, |value, column| value << 1 | self.bit(column) as u128))
+    }
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::new(1, 1, "a binary number"));
+        }
+
+        let mut report = Self::zero(s.chars().count());
+        for (column, c) in s.chars().enumerate() {
+            match c {
+                '0' => (),
+                '1' => report.set(column, true),
+                _ => return Err(ParseError::new(1, column + 1, "0 or 1")),
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for column in 0..self.width {
+            write!(f, "{}", if self.bit(column) { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+/// Picks a bit given how many ones and zeros there are in a column.
+pub trait Criterion {
+    fn bit(&self, ones: usize, zeros: usize) -> bool;
+}
+
+impl<F: Fn(usize, usize) -> bool> Criterion for F {
+    fn bit(&self, ones: usize, zeros: usize) -> bool {
+        self(ones, zeros)
+    }
+}
+
+/// The most common bit, or `tie` if there are as many of each.
+pub struct MostCommon {
+    pub tie: bool,
+}
+
+impl Criterion for MostCommon {
+    fn bit(&self, ones: usize, zeros: usize) -> bool {
+        if ones == zeros {
+            self.tie
+        } else {
+            ones > zeros
+        }
+    }
+}
+
+/// The least common bit, or `tie` if there are as many of each.
+pub struct LeastCommon {
+    pub tie: bool,
+}
+
+impl Criterion for LeastCommon {
+    fn bit(&self, ones: usize, zeros: usize) -> bool {
+        if ones == zeros {
+            self.tie
+        } else {
+            ones < zeros
+        }
+    }
+}
+
+/// A whole diagnostic report, with the ones in each column counted up front.
+#[derive(Clone, Debug)]
+pub struct Diagnostics {
+    width: usize,
+    reports: Vec<Report>,
+    ones: Vec<usize>,
+}
+
+impl Diagnostics {
+    /// Parses one report per line, all of them the same width.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut reports = Vec::new();
+
+        for line in parse::lines(input) {
+            let report: Report = line.parse(line.text)?;
+            let width = *width.get_or_insert(report.width());
+            if report.width() != width {
+                return Err(line.error(line.text, format!("a binary number {} bits wide", width)));
+            }
+            reports.push(report);
+        }
+
+        let width = width.ok_or_else(|| ParseError::end(input, "a binary number"))?;
+        let mut ones = vec![0; width];
+        for report in &reports {
+            for column in report.ones() {
+                ones[column] += 1;
+            }
+        }
+
+        Ok(Self {
+            width,
+            reports,
+            ones,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn reports(&self) -> &[Report] {
+        &self.reports
+    }
+
+    /// How many reports have a 1 in each column.
+    pub fn ones(&self) -> &[usize] {
+        &self.ones
+    }
+
+    /// Builds a report out of the bit `criterion` picks for each column.
+    pub fn select(&self, criterion: &impl Criterion) -> Report {
+        let mut report = Report::zero(self.width);
+        for (column, &ones) in self.ones.iter().enumerate() {
+            report.set(column, criterion.bit(ones, self.reports.len() - ones));
+        }
+        report
+    }
+
+    /// Whittles the reports down, column by column, to the ones with the bit
+    /// `criterion` picks from those that are left, until only one is left.
+    /// There's no rating if the criterion rules every report out.
+    pub fn rating(&self, criterion: &impl Criterion) -> Option<Report> {
+        let mut candidates: Vec<&Report> = self.reports.iter().collect();
+
+        for column in 0..self.width {
+            if candidates.len() <= 1 {
+                break;
+            }
+
+            let ones = candidates.iter().filter(|r| r.bit(column)).count();
+            let bit = criterion.bit(ones, candidates.len() - ones);
+            candidates.retain(|r| r.bit(column) == bit);
+        }
+
+        candidates.first().map(|&report| report.clone())
+    }
+
+    pub fn gamma(&self) -> Report {
+        self.select(&MostCommon { tie: false })
+    }
+
+    pub fn epsilon(&self) -> Report {
+        self.select(&LeastCommon { tie: true })
+    }
+
+    pub fn oxygen(&self) -> Option<Report> {
+        self.rating(&MostCommon { tie: true })
+    }
+
+    pub fn co2(&self) -> Option<Report> {
+        self.rating(&LeastCommon { tie: false })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_can_be_wider_than_a_word() {
+        let wide = format!("1{}1", "0".repeat(98));
+        let diagnostics = Diagnostics::parse(&format!("{}\n{}\n", wide, wide)).unwrap();
+
+        assert_eq!(diagnostics.width(), 100);
+        assert_eq!(diagnostics.ones()[0], 2);
+        assert_eq!(diagnostics.ones()[99], 2);
+        assert_eq!(diagnostics.gamma().to_string(), wide);
+        assert_eq!(diagnostics.epsilon().value(), Some((1 << 99) - 2));
+    }
+
+    #[test]
+    fn ragged_and_non_binary_lines_are_errors() {
+        let ragged = Diagnostics::parse("0101\n011\n").unwrap_err();
+        assert_eq!(ragged, ParseError::new(2, 1, "a binary number 4 bits wide"));
+
+        let non_binary = Diagnostics::parse("0101\n0121\n").unwrap_err();
+        assert_eq!(non_binary, ParseError::new(2, 3, "0 or 1"));
+    }
+}
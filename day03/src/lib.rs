@@ -0,0 +1,31 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+pub mod diagnostics;
+
+use diagnostics::{Diagnostics, Report};
+
+fn product(a: Report, b: Report) -> Answer {
+    let product = a.value().zip(b.value()).and_then(|(a, b)| a.checked_mul(b));
+    match product.and_then(|product| i128::try_from(product).ok()) {
+        Some(product) => Answer::Number(product),
+        None => panic!("{} times {} is too big", a, b),
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Diagnostics;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Diagnostics::parse(input)
+    }
+
+    fn part1(diagnostics: &Self::Input) -> Answer {
+        product(diagnostics.gamma(), diagnostics.epsilon())
+    }
+
+    fn part2(diagnostics: &Self::Input) -> Answer {
+        product(diagnostics.oxygen().unwrap(), diagnostics.co2().unwrap())
+    }
+}
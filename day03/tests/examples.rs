@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day03::Day03>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,80 @@
+//! Plays a tournament of 10,000 random boards, with the indexed simulator
+//! and with a simple one that checks every cell of every board on every draw.
+//!
+//! Run with `cargo bench -p day04 --bench tournament`.
+
+use aoc_common::bench::{measure, Budget};
+use day04::{tournament, Board, WinCondition, ROWS_AND_COLUMNS};
+
+const BOARDS: usize = 10_000;
+const NUMBERS: u32 = 100;
+
+/// A xorshift generator, which is plenty random enough for bingo.
+struct Random(u64);
+
+impl Random {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+
+    fn shuffled(&mut self) -> Vec<u32> {
+        let mut numbers: Vec<u32> = (0..NUMBERS).collect();
+        for i in (1..numbers.len()).rev() {
+            numbers.swap(i, self.below(i + 1));
+        }
+        numbers
+    }
+}
+
+/// Plays every board by marking and checking it in full on every draw,
+/// returning how many boards won. The boards are all the same size, so their
+/// lines are only worked out once.
+fn scan(numbers: &[u32], boards: &[Board]) -> usize {
+    let lines = ROWS_AND_COLUMNS[..].lines(boards[0].width(), boards[0].height());
+    let mut boards = boards.to_vec();
+    let mut finished = vec![false; boards.len()];
+
+    for &number in numbers {
+        for (board, finished) in boards.iter_mut().zip(finished.iter_mut()) {
+            if !*finished {
+                board.mark(number);
+                *finished = lines
+                    .iter()
+                    .any(|line| line.iter().all(|&position| board.is_marked(position)));
+            }
+        }
+    }
+
+    finished.iter().filter(|&&f| f).count()
+}
+
+fn main() {
+    let mut random = Random(0x2021_1204);
+    let numbers = random.shuffled();
+    let boards: Vec<Board> = (0..BOARDS)
+        .map(|_| {
+            Board::new(
+                random.shuffled()[..25]
+                    .chunks(5)
+                    .map(<[u32]>::to_vec)
+                    .collect(),
+            )
+        })
+        .collect();
+
+    let standings = tournament(&numbers, &boards, &ROWS_AND_COLUMNS[..]);
+    assert_eq!(standings.finishing_order.len(), scan(&numbers, &boards));
+
+    let budget = Budget::default();
+    let indexed = measure(&budget, || {
+        tournament(&numbers, &boards, &ROWS_AND_COLUMNS[..])
+    });
+    let scanned = measure(&budget, || scan(&numbers, &boards));
+
+    println!("{} boards, {} numbers", BOARDS, NUMBERS);
+    println!("indexed  {:>12}", format!("{:.3?}", indexed.median));
+    println!("scanned  {:>12}", format!("{:.3?}", scanned.median));
+}
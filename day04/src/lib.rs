@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+
+use aoc_common::grid::Grid;
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    numbers: Grid<u32>,
+    marked: Grid<bool>,
+}
+
+impl Board {
+    /// Builds a board from its rows, which must all be the same length.
+    pub fn new(rows: Vec<Vec<u32>>) -> Self {
+        let numbers = Grid::from_rows(rows);
+        let marked = numbers.map(|_| false);
+        Self { numbers, marked }
+    }
+
+    pub fn width(&self) -> usize {
+        self.numbers.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.numbers.height()
+    }
+
+    pub fn is_marked(&self, position: (usize, usize)) -> bool {
+        self.marked[position]
+    }
+
+    /// Marks every cell holding `number`.
+    pub fn mark(&mut self, number: u32) {
+        for position in self.numbers.positions() {
+            if self.numbers[position] == number {
+                self.marked[position] = true;
+            }
+        }
+    }
+
+    fn unmarked_sum(&self) -> u32 {
+        self.numbers
+            .positions()
+            .filter(|&position| !self.marked[position])
+            .map(|position| self.numbers[position])
+            .sum()
+    }
+}
+
+/// What a board needs to have marked to win: all of any one of its lines.
+pub trait WinCondition {
+    /// The lines on a board of the given size, each a set of cells.
+    fn lines(&self, width: usize, height: usize) -> Vec<Vec<(usize, usize)>>;
+
+    fn won(&self, board: &Board) -> bool {
+        self.lines(board.width(), board.height())
+            .iter()
+            .any(|line| line.iter().all(|&position| board.is_marked(position)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pattern {
+    /// Any whole row.
+    Rows,
+    /// Any whole column.
+    Columns,
+    /// Either corner-to-corner diagonal, on a square board.
+    Diagonals,
+    /// All four corners.
+    Corners,
+    /// Every cell on the board.
+    Blackout,
+}
+
+impl WinCondition for Pattern {
+    fn lines(&self, width: usize, height: usize) -> Vec<Vec<(usize, usize)>> {
+        match self {
+            Self::Rows => (0..height)
+                .map(|y| (0..width).map(|x| (x, y)).collect())
+                .collect(),
+            Self::Columns => (0..width)
+                .map(|x| (0..height).map(|y| (x, y)).collect())
+                .collect(),
+            Self::Diagonals if width == height && width > 0 => vec![
+                (0..width).map(|i| (i, i)).collect(),
+                (0..width).map(|i| (width - 1 - i, i)).collect(),
+            ],
+            Self::Diagonals => Vec::new(),
+            Self::Corners if width > 0 && height > 0 => vec![vec![
+                (0, 0),
+                (width - 1, 0),
+                (0, height - 1),
+                (width - 1, height - 1),
+            ]],
+            Self::Corners => Vec::new(),
+            Self::Blackout => vec![(0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .collect()],
+        }
+    }
+}
+
+/// Any of the conditions will do.
+impl<W: WinCondition> WinCondition for [W] {
+    fn lines(&self, width: usize, height: usize) -> Vec<Vec<(usize, usize)>> {
+        self.iter()
+            .flat_map(|condition| condition.lines(width, height))
+            .collect()
+    }
+}
+
+/// The standard rules: a whole row or column.
+pub const ROWS_AND_COLUMNS: [Pattern; 2] = [Pattern::Rows, Pattern::Columns];
+
+/// How a board finished: the number that completed it, which draw that was
+/// (counting from 0) and the sum of the numbers still unmarked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    pub number: u32,
+    pub draw: usize,
+    pub unmarked_sum: u32,
+}
+
+impl Win {
+    pub fn score(&self) -> u32 {
+        self.unmarked_sum * self.number
+    }
+}
+
+/// The outcome of playing every board to the end of the draw.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Standings {
+    /// Every board that won, in the order they won. Boards that win on the
+    /// same draw are in the order they were given.
+    pub finishing_order: Vec<Win>,
+    /// The boards still without a bingo once the numbers ran out.
+    pub never_won: Vec<usize>,
+}
+
+/// The lines on boards of one size, and which of them each cell is in.
+struct Layout {
+    lengths: Vec<usize>,
+    lines: Grid<Vec<usize>>,
+}
+
+impl Layout {
+    fn new(rules: &(impl WinCondition + ?Sized), width: usize, height: usize) -> Self {
+        let mut lines = Grid::new(width, height, Vec::new());
+        let mut lengths = Vec::new();
+
+        for (index, line) in rules.lines(width, height).into_iter().enumerate() {
+            for &position in &line {
+                lines[position].push(index);
+            }
+            lengths.push(line.len());
+        }
+
+        Self { lengths, lines }
+    }
+}
+
+/// A board and a position on it.
+type Cell = (usize, (usize, usize));
+
+/// Plays every board until it wins or the numbers run out. Each number is
+/// looked up in an index of where it appears on every board, and each board
+/// counts how much of each of its lines is marked, so that a draw only
+/// touches the cells it marks.
+pub fn tournament(
+    numbers: &[u32],
+    boards: &[Board],
+    rules: &(impl WinCondition + ?Sized),
+) -> Standings {
+    let mut boards = boards.to_vec();
+    let mut layouts = HashMap::new();
+    let mut index: HashMap<u32, Vec<Cell>> = HashMap::new();
+    let mut hits = Vec::new();
+    let mut unmarked_sums = Vec::new();
+
+    for (b, board) in boards.iter().enumerate() {
+        let size = (board.width(), board.height());
+        let layout = layouts
+            .entry(size)
+            .or_insert_with(|| Layout::new(rules, size.0, size.1));
+
+        for position in board.numbers.positions() {
+            index
+                .entry(board.numbers[position])
+                .or_default()
+                .push((b, position));
+        }
+        hits.push(vec![0; layout.lengths.len()]);
+        unmarked_sums.push(board.unmarked_sum());
+    }
+
+    let mut finished = vec![false; boards.len()];
+    let mut finishing_order = Vec::new();
+
+    for (draw, &number) in numbers.iter().enumerate() {
+        // The index is in board order, so winners are found in board order.
+        let mut winners: Vec<usize> = Vec::new();
+
+        for &(b, position) in index.get(&number).into_iter().flatten() {
+            let board = &mut boards[b];
+            if finished[b] || board.marked[position] {
+                continue;
+            }
+            board.marked[position] = true;
+            unmarked_sums[b] -= number;
+
+            let layout = &layouts[&(board.width(), board.height())];
+            for &line in &layout.lines[position] {
+                hits[b][line] += 1;
+                if hits[b][line] == layout.lengths[line] && winners.last() != Some(&b) {
+                    winners.push(b);
+                }
+            }
+        }
+
+        for b in winners {
+            finished[b] = true;
+            finishing_order.push(Win {
+                board: b,
+                number,
+                draw,
+                unmarked_sum: unmarked_sums[b],
+            });
+        }
+    }
+
+    let never_won = (0..boards.len()).filter(|&b| !finished[b]).collect();
+
+    Standings {
+        finishing_order,
+        never_won,
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = (Vec<u32>, Vec<Board>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input);
+
+        let first = lines
+            .next()
+            .ok_or_else(|| ParseError::end(input, "the numbers to draw"))?;
+        let numbers: Vec<u32> = first
+            .text
+            .split(',')
+            .map(|n| first.field(n, "a number"))
+            .collect::<Result<_, _>>()?;
+        lines.next();
+
+        let mut boards = Vec::new();
+        let mut rows: Vec<Vec<u32>> = Vec::new();
+
+        for line in lines {
+            if line.text.trim().is_empty() {
+                if !rows.is_empty() {
+                    boards.push(Board::new(std::mem::take(&mut rows)));
+                }
+                continue;
+            }
+
+            let row = line
+                .text
+                .split_whitespace()
+                .map(|n| line.field(n, "a number"))
+                .collect::<Result<Vec<u32>, _>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(line.error(line.text, format!("a row of {} numbers", first.len())));
+                }
+            }
+            rows.push(row);
+        }
+        if !rows.is_empty() {
+            boards.push(Board::new(rows));
+        }
+
+        Ok((numbers, boards))
+    }
+
+    fn part1((numbers, boards): &Self::Input) -> Answer {
+        let standings = tournament(numbers, boards, &ROWS_AND_COLUMNS[..]);
+        standings
+            .finishing_order
+            .first()
+            .expect("nobody won")
+            .score()
+            .into()
+    }
+
+    fn part2((numbers, boards): &Self::Input) -> Answer {
+        let standings = tournament(numbers, boards, &ROWS_AND_COLUMNS[..]);
+        standings
+            .finishing_order
+            .last()
+            .expect("nobody won")
+            .score()
+            .into()
+    }
+}
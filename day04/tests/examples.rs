@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day04::Day04>(env!("CARGO_MANIFEST_DIR"));
+}
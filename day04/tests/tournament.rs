@@ -0,0 +1,48 @@
+use aoc_common::Solution;
+use day04::{tournament, Day04, Pattern, ROWS_AND_COLUMNS};
+
+#[test]
+fn every_board_is_ranked() {
+    let input =
+        std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/test-input")).unwrap();
+    let (numbers, boards) = Day04::parse(&input).unwrap();
+
+    let standings = tournament(&numbers, &boards, &ROWS_AND_COLUMNS[..]);
+    let order: Vec<_> = standings
+        .finishing_order
+        .iter()
+        .map(|win| (win.board, win.number, win.draw))
+        .collect();
+    assert_eq!(order, [(2, 24, 11), (0, 16, 13), (1, 13, 14)]);
+    assert!(standings.never_won.is_empty());
+
+    let standings = tournament(&numbers[..12], &boards, &ROWS_AND_COLUMNS[..]);
+    assert_eq!(standings.finishing_order.len(), 1);
+    assert_eq!(standings.never_won, [0, 1]);
+}
+
+#[test]
+fn boards_can_be_any_size_and_win_other_ways() {
+    let (numbers, boards) = Day04::parse("1,5,9,3,7\n\n1 2 3\n4 5 6\n7 8 9\n").unwrap();
+    assert_eq!((boards[0].width(), boards[0].height()), (3, 3));
+
+    let wins = |rules: &[Pattern]| {
+        tournament(&numbers, &boards, rules)
+            .finishing_order
+            .first()
+            .map(|win| win.draw)
+    };
+    assert_eq!(wins(&ROWS_AND_COLUMNS), None);
+    assert_eq!(wins(&[Pattern::Diagonals]), Some(2));
+    assert_eq!(wins(&[Pattern::Corners]), Some(4));
+    assert_eq!(wins(&[Pattern::Blackout]), None);
+}
+
+#[test]
+fn ragged_boards_are_rejected() {
+    let error = Day04::parse("1,2\n\n1 2 3\n4 5\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "<input>:4:1: expected a row of 3 numbers"
+    );
+}
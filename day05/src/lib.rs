@@ -0,0 +1,360 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use aoc_common::heatmap::Heatmap;
+use aoc_common::parse::{self, InputLine};
+use aoc_common::{Answer, ParseError, Solution};
+use num_bigint::{BigInt, Sign};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point(pub i64, pub i64);
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let point = InputLine { number: 1, text: s };
+        let [x, y] = point.split(",", "a point like 0,9")?;
+        Ok(Self(
+            point.field(x, "a coordinate")?,
+            point.field(y, "a coordinate")?,
+        ))
+    }
+}
+
+impl std::fmt::Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line(pub Point, pub Point);
+
+/// How a line is turned into points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rasterisation {
+    /// Exactly the points with integer coordinates that lie on the line,
+    /// found by stepping along it in strides of the gcd of its extents.
+    #[default]
+    Lattice,
+    /// Bresenham's algorithm: one point for every step along the longer
+    /// extent, as close to the line as possible. That's the same as
+    /// [`Rasterisation::Lattice`] for horizontal, vertical and 45° lines,
+    /// but draws a solid line at any other slope.
+    Bresenham,
+}
+
+impl Line {
+    pub fn is_straight(&self) -> bool {
+        self.0 .0 == self.1 .0 || self.0 .1 == self.1 .1
+    }
+
+    /// In i128, as a line can span more than an i64 can hold.
+    fn delta(&self) -> (i128, i128) {
+        (
+            self.1 .0 as i128 - self.0 .0 as i128,
+            self.1 .1 as i128 - self.0 .1 as i128,
+        )
+    }
+
+    /// Every point on the line with integer coordinates, from start to end.
+    pub fn points(&self) -> Raster {
+        self.rasterise(Rasterisation::Lattice)
+    }
+
+    pub fn rasterise(&self, rasterisation: Rasterisation) -> Raster {
+        let (dx, dy) = self.delta();
+        let steps = match rasterisation {
+            Rasterisation::Lattice => num_integer::gcd(dx, dy),
+            Rasterisation::Bresenham => dx.abs().max(dy.abs()),
+        };
+
+        Raster {
+            rasterisation,
+            current: (self.0 .0 as i128, self.0 .1 as i128),
+            remaining: steps + 1,
+            stride: match (rasterisation, steps) {
+                (_, 0) => (0, 0),
+                (Rasterisation::Lattice, _) => (dx / steps, dy / steps),
+                (Rasterisation::Bresenham, _) => (dx.signum(), dy.signum()),
+            },
+            delta: (dx.abs(), -dy.abs()),
+            error: dx.abs() - dy.abs(),
+        }
+    }
+
+    /// Whether `point` lies exactly on the line.
+    pub fn contains(&self, point: Point) -> bool {
+        let offset = (
+            point.0 as i128 - self.0 .0 as i128,
+            point.1 as i128 - self.0 .1 as i128,
+        );
+
+        cross(self.delta(), offset).sign() == Sign::NoSign
+            && (self.0 .0.min(self.1 .0)..=self.0 .0.max(self.1 .0)).contains(&point.0)
+            && (self.0 .1.min(self.1 .1)..=self.0 .1.max(self.1 .1)).contains(&point.1)
+    }
+
+    /// The points with integer coordinates that lie exactly on both lines,
+    /// worked out from the geometry rather than by drawing the lines.
+    pub fn intersections(&self, other: &Line) -> Vec<Point> {
+        let (a, b) = (self.delta(), other.delta());
+
+        if a == (0, 0) {
+            return [self.0]
+                .into_iter()
+                .filter(|&p| other.contains(p))
+                .collect();
+        } else if b == (0, 0) {
+            return [other.0]
+                .into_iter()
+                .filter(|&p| self.contains(p))
+                .collect();
+        }
+
+        let offset = (
+            other.0 .0 as i128 - self.0 .0 as i128,
+            other.0 .1 as i128 - self.0 .1 as i128,
+        );
+        let denominator = cross(a, b);
+
+        if denominator.sign() == Sign::NoSign {
+            if cross(a, offset).sign() != Sign::NoSign {
+                return Vec::new();
+            }
+
+            // The same line: the lattice points on it are this line's start
+            // plus multiples of its stride, and the other line's ends are
+            // two of them, so find the range of multiples that lands within
+            // both lines.
+            let g = num_integer::gcd(a.0, a.1);
+            let stride = (a.0 / g, a.1 / g);
+            let along = |p: Point| {
+                if stride.0 != 0 {
+                    (p.0 as i128 - self.0 .0 as i128) / stride.0
+                } else {
+                    (p.1 as i128 - self.0 .1 as i128) / stride.1
+                }
+            };
+            let (k1, k2) = (along(other.0), along(other.1));
+            let (first, last) = (k1.min(k2).max(0), k1.max(k2).min(g));
+
+            return (first..=last)
+                .map(|k| {
+                    Point(
+                        (self.0 .0 as i128 + k * stride.0) as i64,
+                        (self.0 .1 as i128 + k * stride.1) as i64,
+                    )
+                })
+                .collect();
+        }
+
+        // Where self.0 + t * a meets other.0 + u * b, with t and u as
+        // fractions over the denominator.
+        let (t, u) = (cross(offset, b), cross(offset, a));
+        let (t, u, denominator) = if denominator.sign() == Sign::Minus {
+            (-t, -u, -denominator)
+        } else {
+            (t, u, denominator)
+        };
+        let within = |n: &BigInt| n.sign() != Sign::Minus && n <= &denominator;
+        if !within(&t) || !within(&u) {
+            return Vec::new();
+        }
+
+        let x = BigInt::from(self.0 .0) * &denominator + &t * a.0;
+        let y = BigInt::from(self.0 .1) * &denominator + &t * a.1;
+        if (&x % &denominator).sign() != Sign::NoSign || (&y % &denominator).sign() != Sign::NoSign
+        {
+            return Vec::new();
+        }
+
+        // It's on both lines, so it fits.
+        let coordinate = |n: BigInt| i64::try_from(n / &denominator).unwrap();
+        vec![Point(coordinate(x), coordinate(y))]
+    }
+}
+
+/// Worked out in a BigInt, as the products of deltas as long as an i64 can
+/// span don't fit in an i128.
+fn cross(a: (i128, i128), b: (i128, i128)) -> BigInt {
+    BigInt::from(a.0) * b.1 - BigInt::from(a.1) * b.0
+}
+
+/// The points of a line, in order from its start.
+pub struct Raster {
+    rasterisation: Rasterisation,
+    current: (i128, i128),
+    remaining: i128,
+    stride: (i128, i128),
+    /// Bresenham's |dx| and -|dy|, and its running error.
+    delta: (i128, i128),
+    error: i128,
+}
+
+impl Iterator for Raster {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let point = self.current;
+
+        match self.rasterisation {
+            Rasterisation::Lattice => {
+                self.current = (point.0 + self.stride.0, point.1 + self.stride.1);
+            }
+            Rasterisation::Bresenham => {
+                let doubled = 2 * self.error;
+                if doubled >= self.delta.1 {
+                    self.error += self.delta.1;
+                    self.current.0 += self.stride.0;
+                }
+                if doubled <= self.delta.0 {
+                    self.error += self.delta.0;
+                    self.current.1 += self.stride.1;
+                }
+            }
+        }
+
+        Some(Point(point.0 as i64, point.1 as i64))
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let line = InputLine { number: 1, text: s };
+        let [start, end] = line.split(" -> ", "a line like 0,9 -> 5,9")?;
+
+        Ok(Self(line.parse(start)?, line.parse(end)?))
+    }
+}
+
+impl std::fmt::Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.0, self.1)
+    }
+}
+
+/// How many lines cross each point, kept only for the points some line
+/// crosses, so that lines can be anywhere at all.
+#[derive(Clone, Debug, Default)]
+pub struct VentMap {
+    rasterisation: Rasterisation,
+    counts: HashMap<Point, u32>,
+}
+
+impl VentMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rasterisation(rasterisation: Rasterisation) -> Self {
+        Self {
+            rasterisation,
+            ..Self::default()
+        }
+    }
+
+    pub fn mark_line(&mut self, line: &Line) {
+        for point in line.rasterise(self.rasterisation) {
+            *self.counts.entry(point).or_default() += 1;
+        }
+    }
+
+    /// How many lines cross `point`.
+    pub fn count(&self, point: Point) -> u32 {
+        self.counts.get(&point).copied().unwrap_or(0)
+    }
+
+    /// Every point some line crosses, and how many lines cross it, in no
+    /// particular order.
+    pub fn cells(&self) -> impl Iterator<Item = (Point, u32)> + '_ {
+        self.counts.iter().map(|(&point, &count)| (point, count))
+    }
+
+    /// The smallest and largest corners of a box around every marked point.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.counts.keys().fold(None, |bounds, &p| match bounds {
+            None => Some((p, p)),
+            Some((min, max)) => Some((
+                Point(min.0.min(p.0), min.1.min(p.1)),
+                Point(max.0.max(p.0), max.1.max(p.1)),
+            )),
+        })
+    }
+
+    pub fn count_overlaps(&self) -> usize {
+        self.counts.values().filter(|&&count| count > 1).count()
+    }
+}
+
+/// Counts the points where lines overlap by intersecting every pair of
+/// lines, to check a [`VentMap`] drawn with [`Rasterisation::Lattice`].
+/// Bresenham's points aren't all exactly on their lines, so there's nothing
+/// to check those against.
+pub fn exact_overlaps(lines: &[Line]) -> usize {
+    let mut points = HashSet::new();
+
+    for (i, a) in lines.iter().enumerate() {
+        for b in &lines[i + 1..] {
+            points.extend(a.intersections(b));
+        }
+    }
+
+    points.len()
+}
+
+fn vent_map(lines: &[Line], diagonals: bool) -> VentMap {
+    let mut map = VentMap::new();
+
+    for line in lines.iter().filter(|line| diagonals || line.is_straight()) {
+        map.mark_line(line);
+    }
+
+    map
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<Line> = parse::lines(input)
+            .map(|line| line.parse(line.text))
+            .collect::<Result<_, _>>()?;
+
+        if lines.is_empty() {
+            return Err(ParseError::end(input, "a line"));
+        }
+
+        Ok(lines)
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        vent_map(lines, false).count_overlaps().into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        vent_map(lines, true).count_overlaps().into()
+    }
+
+    fn heatmap(input: &str, part: u8) -> Result<Option<Heatmap>, ParseError> {
+        let diagonals = match part {
+            1 => false,
+            2 => true,
+            _ => return Ok(None),
+        };
+        let map = vent_map(&Self::parse(input)?, diagonals);
+        Ok(Some(Heatmap::from_cells(
+            map.cells()
+                .map(|(point, count)| ((point.0, point.1), count as f64)),
+        )))
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day05::Day05>(env!("CARGO_MANIFEST_DIR"));
+}
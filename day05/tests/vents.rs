@@ -0,0 +1,135 @@
+use day05::{exact_overlaps, Line, Point, Rasterisation, VentMap};
+
+#[test]
+fn lines_can_be_anywhere() {
+    let far = 1 << 40;
+    let lines: Vec<Line> = [
+        "-3,-3 -> 3,3",
+        "-3,3 -> 3,-3",
+        "1000000000000,5 -> 1000000000000,-5",
+    ]
+    .iter()
+    .map(|line| line.parse().unwrap())
+    .chain([
+        Line(Point(far, 0), Point(far + 2, 0)),
+        Line(Point(far + 1, -1), Point(far + 1, 1)),
+    ])
+    .collect();
+
+    let mut map = VentMap::new();
+    for line in &lines {
+        map.mark_line(line);
+    }
+
+    assert_eq!(map.count(Point(0, 0)), 2);
+    assert_eq!(map.count(Point(far + 1, 0)), 2);
+    assert_eq!(map.count(Point(7, 7)), 0);
+    assert_eq!(map.count_overlaps(), 2);
+    assert_eq!(map.bounds(), Some((Point(-3, -5), Point(far + 2, 5))));
+}
+
+#[test]
+fn lines_of_any_slope_are_rasterised() {
+    let line: Line = "0,0 -> 6,4".parse().unwrap();
+
+    let lattice: Vec<Point> = line.points().collect();
+    assert_eq!(lattice, [Point(0, 0), Point(3, 2), Point(6, 4)]);
+
+    let bresenham: Vec<Point> = line.rasterise(Rasterisation::Bresenham).collect();
+    assert_eq!(bresenham.len(), 7);
+    assert_eq!(bresenham.first(), Some(&Point(0, 0)));
+    assert_eq!(bresenham.last(), Some(&Point(6, 4)));
+    assert!(bresenham
+        .windows(2)
+        .all(|w| (w[1].0 - w[0].0) == 1 && (0..=1).contains(&(w[1].1 - w[0].1))));
+}
+
+#[test]
+fn intersections_agree_with_the_map() {
+    // A small linear congruential generator, for lines at every angle,
+    // including parallel, overlapping and zero-length ones.
+    let mut seed = 2021u64;
+    let mut coordinate = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as i64 % 13 - 6
+    };
+    let lines: Vec<Line> = (0..200)
+        .map(|_| {
+            Line(
+                Point(coordinate(), coordinate()),
+                Point(coordinate(), coordinate()),
+            )
+        })
+        .collect();
+
+    let mut map = VentMap::new();
+    for line in &lines {
+        map.mark_line(line);
+    }
+
+    assert_eq!(map.count_overlaps(), exact_overlaps(&lines));
+}
+
+#[test]
+fn far_lines_cross_exactly() {
+    let far = 1 << 41;
+    let lines = [
+        Line(Point(0, 0), Point(far, far)),
+        Line(Point(0, far), Point(far, 0)),
+        Line(Point(-far, -far), Point(2, 2)),
+        Line(Point(-far, 1), Point(far, 1)),
+    ];
+
+    let crossings: Vec<Point> = lines[0].intersections(&lines[1]);
+    assert_eq!(crossings, [Point(far / 2, far / 2)]);
+    assert_eq!(lines[0].intersections(&lines[2]).len(), 3);
+    // (far / 2, far / 2), the three points the first and third lines share
+    // (the fourth crosses both at one of them), and (far - 1, 1).
+    assert_eq!(exact_overlaps(&lines), 5);
+}
+
+#[test]
+fn lines_as_long_as_they_get() {
+    let across = Line(Point(i64::MIN, 0), Point(i64::MAX, 0));
+    let down = Line(Point(0, i64::MIN), Point(0, i64::MAX));
+    assert!(across.contains(Point(0, 0)));
+    assert_eq!(across.intersections(&down), [Point(0, 0)]);
+
+    let tail = Line(Point(i64::MAX - 2, 0), Point(i64::MAX, 0));
+    assert_eq!(
+        across.intersections(&tail),
+        [
+            Point(i64::MAX - 2, 0),
+            Point(i64::MAX - 1, 0),
+            Point(i64::MAX, 0)
+        ]
+    );
+
+    // They cross at (-0.5, -0.5), between lattice points.
+    let diagonal = Line(Point(i64::MIN, i64::MIN), Point(i64::MAX, i64::MAX));
+    let anti = Line(Point(i64::MIN, i64::MAX), Point(i64::MAX, i64::MIN));
+    assert_eq!(diagonal.intersections(&anti), []);
+    let anti = Line(Point(i64::MIN + 1, i64::MAX), Point(i64::MAX, i64::MIN + 1));
+    assert_eq!(diagonal.intersections(&anti), [Point(0, 0)]);
+
+    let steep = Line(Point(i64::MIN, 0), Point(i64::MAX, 1));
+    let points: Vec<Point> = steep.points().collect();
+    assert_eq!(points, [steep.0, steep.1]);
+}
+
+#[test]
+fn crossings_far_from_the_origin() {
+    let far = 1 << 44;
+
+    // The denominator and t are both around 2^90.
+    let a = Line(Point(0, 0), Point(2 * far, 2 * far - 2));
+    let b = Line(Point(0, 2 * far - 1), Point(2 * far, -1));
+    assert_eq!(a.intersections(&b), [Point(far, far - 1)]);
+
+    // These cross between lattice points.
+    let a = Line(Point(0, 0), Point(far, far - 1));
+    let b = Line(Point(0, far), Point(far, 0));
+    assert_eq!(a.intersections(&b), []);
+}
@@ -0,0 +1,231 @@
+use aoc_common::render::{Headless, Renderer};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use num_bigint::BigUint;
+
+/// How lanternfish breed: a fish whose timer runs out goes back to `reset`
+/// and has a baby with a timer of `newborn`. Counts can be kept modulo a
+/// modulus, for horizons too long for the counts themselves to fit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Model {
+    pub reset: usize,
+    pub newborn: usize,
+    modulus: Option<BigUint>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self::new(6, 8)
+    }
+}
+
+/// A day's population, as handed to a [`Renderer`]: how many fish there are
+/// with each timer.
+pub struct Generation<'a> {
+    pub day: u64,
+    pub counts: &'a [BigUint],
+}
+
+impl Generation<'_> {
+    pub fn total(&self) -> BigUint {
+        self.counts.iter().sum()
+    }
+}
+
+/// Prints the population every day.
+struct Trace;
+
+impl Renderer<Generation<'_>> for Trace {
+    fn frame(&mut self, generation: &Generation) {
+        println!("day {:5}: {} fish", generation.day, generation.total());
+    }
+}
+
+type Matrix = Vec<Vec<BigUint>>;
+
+impl Model {
+    pub fn new(reset: usize, newborn: usize) -> Self {
+        Self {
+            reset,
+            newborn,
+            modulus: None,
+        }
+    }
+
+    /// The same model, keeping counts modulo `modulus`, or `None` if it's 0.
+    pub fn with_modulus(self, modulus: impl Into<BigUint>) -> Option<Self> {
+        let modulus = modulus.into();
+        if modulus == BigUint::default() {
+            return None;
+        }
+
+        Some(Self {
+            modulus: Some(modulus),
+            ..self
+        })
+    }
+
+    pub fn modulus(&self) -> Option<&BigUint> {
+        self.modulus.as_ref()
+    }
+
+    /// How many timer values there are, given fish starting with `counts`.
+    fn states(&self, counts: &[u64]) -> usize {
+        counts.len().max(self.reset.max(self.newborn) + 1)
+    }
+
+    fn reduce(&self, n: BigUint) -> BigUint {
+        match &self.modulus {
+            Some(modulus) => n % modulus,
+            None => n,
+        }
+    }
+
+    /// Counts the fish after `days` days, stepping a day at a time, where
+    /// `counts[t]` fish start with a timer of `t`.
+    pub fn simulate(
+        &self,
+        counts: &[u64],
+        days: u64,
+        trace: &mut impl for<'a> Renderer<Generation<'a>>,
+    ) -> BigUint {
+        let mut counts: Vec<BigUint> = (0..self.states(counts))
+            .map(|t| self.reduce(counts.get(t).copied().unwrap_or(0).into()))
+            .collect();
+        trace.begin(&Generation {
+            day: 0,
+            counts: &counts,
+        });
+
+        for day in 1..=days {
+            counts.rotate_left(1);
+            let breeding = counts.last().unwrap().clone();
+            let last = counts.len() - 1;
+            counts[last] = BigUint::default();
+            counts[self.reset] += &breeding;
+            counts[self.newborn] += breeding;
+            if self.modulus.is_some() {
+                for count in counts.iter_mut() {
+                    *count = self.reduce(std::mem::take(count));
+                }
+            }
+            trace.frame(&Generation {
+                day,
+                counts: &counts,
+            });
+        }
+
+        trace.finish(&Generation {
+            day: days,
+            counts: &counts,
+        });
+        self.reduce(counts.iter().sum())
+    }
+
+    /// Counts the fish after `days` days like [`Model::simulate`], but by
+    /// raising the day-to-day transition to the power of `days`, which takes
+    /// time proportional to the number of digits in `days`.
+    pub fn after(&self, counts: &[u64], days: u64) -> BigUint {
+        let states = self.states(counts);
+
+        // Tomorrow's count for timer t is transition[t] · today's counts.
+        let mut transition: Matrix = vec![vec![BigUint::default(); states]; states];
+        for t in 0..states - 1 {
+            transition[t][t + 1] = 1u32.into();
+        }
+        transition[self.reset][0] += 1u32;
+        transition[self.newborn][0] += 1u32;
+
+        let power = self.power(transition, days);
+        let total: BigUint = power
+            .iter()
+            .flat_map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(t, n)| n * counts.get(t).copied().unwrap_or(0))
+            })
+            .sum();
+        self.reduce(total)
+    }
+
+    fn power(&self, mut base: Matrix, mut exponent: u64) -> Matrix {
+        let states = base.len();
+        let mut result: Matrix = (0..states)
+            .map(|i| {
+                (0..states)
+                    .map(|j| BigUint::from((i == j) as u32))
+                    .collect()
+            })
+            .collect();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.multiply(&result, &base);
+            }
+            base = self.multiply(&base, &base);
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    fn multiply(&self, a: &Matrix, b: &Matrix) -> Matrix {
+        let states = a.len();
+        (0..states)
+            .map(|i| {
+                (0..states)
+                    .map(|j| self.reduce((0..states).map(|k| &a[i][k] * &b[k][j]).sum()))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn answer(n: BigUint) -> Answer {
+    match i128::try_from(&n) {
+        Ok(n) => Answer::Number(n),
+        Err(_) => Answer::Text(n.to_string()),
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    /// How many fish start with each timer.
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::end(input, "a list of timers"))?;
+        let mut counts = vec![0; 9];
+
+        for t in line.text.trim_end().split(',') {
+            match line.field::<usize>(t, "a timer from 0 to 8")? {
+                timer @ 0..=8 => counts[timer] += 1,
+                _ => return Err(line.error(t, "a timer from 0 to 8")),
+            }
+        }
+
+        Ok(counts)
+    }
+
+    fn part1(counts: &Self::Input) -> Answer {
+        answer(Model::default().simulate(counts, 80, &mut Headless))
+    }
+
+    fn part2(counts: &Self::Input) -> Answer {
+        answer(Model::default().after(counts, 256))
+    }
+
+    fn visualise(input: &str, part: u8) -> Result<Option<Answer>, ParseError> {
+        let counts = Self::parse(input)?;
+        let days = match part {
+            1 => 80,
+            2 => 256,
+            _ => return Ok(None),
+        };
+        Ok(Some(answer(
+            Model::default().simulate(&counts, days, &mut Trace),
+        )))
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day06::Day06>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,43 @@
+use aoc_common::render::Headless;
+use day06::Model;
+
+const COUNTS: [u64; 9] = [0, 1, 1, 2, 1, 0, 0, 0, 0];
+
+#[test]
+fn both_ways_of_counting_agree() {
+    let models = [
+        Model::default(),
+        Model::new(2, 4),
+        Model::default().with_modulus(1_000_000_007u32).unwrap(),
+    ];
+
+    for model in models {
+        for days in [0, 1, 18, 80, 300, 1000] {
+            assert_eq!(
+                model.simulate(&COUNTS, days, &mut Headless),
+                model.after(&COUNTS, days),
+                "{:?} after {} days",
+                model,
+                days
+            );
+        }
+    }
+}
+
+#[test]
+fn counts_go_far_beyond_a_machine_word() {
+    let model = Model::default();
+    assert_eq!(model.after(&COUNTS, 18), 26u32.into());
+    assert!(model.after(&COUNTS, 2000).bits() > 128);
+
+    let model = Model::default().with_modulus(1_000_000_007u32).unwrap();
+    assert_eq!(
+        model.after(&COUNTS, 1_000_000_000_000),
+        995_077_479u32.into()
+    );
+}
+
+#[test]
+fn counts_cant_be_kept_modulo_0() {
+    assert_eq!(Model::default().with_modulus(0u32), None);
+}
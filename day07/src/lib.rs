@@ -0,0 +1,180 @@
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+/// How to find the cheapest position, given how fuel cost grows with
+/// distance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Optimiser {
+    /// The median position, which is cheapest when fuel is linear.
+    Median,
+    /// The positions within a step of the mean, one of which is cheapest
+    /// when fuel grows with the square of the distance, or close to it.
+    MeanNeighbourhood,
+    /// Narrows in on the cheapest position a third at a time. This works
+    /// for any cost that never grows more slowly as the distance grows, as
+    /// the total is then convex.
+    TernarySearch,
+}
+
+/// How much fuel a crab uses to move a given distance.
+pub trait FuelCost {
+    fn cost(&self, distance: u64) -> u128;
+
+    /// The quickest way to find the cheapest position for this cost.
+    fn optimiser(&self) -> Optimiser {
+        Optimiser::TernarySearch
+    }
+}
+
+/// A unit of fuel per step.
+pub struct Linear;
+
+impl FuelCost for Linear {
+    fn cost(&self, distance: u64) -> u128 {
+        distance as u128
+    }
+
+    fn optimiser(&self) -> Optimiser {
+        Optimiser::Median
+    }
+}
+
+/// Each step costs one more than the one before.
+pub struct Triangular;
+
+impl FuelCost for Triangular {
+    fn cost(&self, distance: u64) -> u128 {
+        let distance = distance as u128;
+        distance * (distance + 1) / 2
+    }
+
+    fn optimiser(&self) -> Optimiser {
+        Optimiser::MeanNeighbourhood
+    }
+}
+
+/// The square of the distance.
+pub struct Quadratic;
+
+impl FuelCost for Quadratic {
+    fn cost(&self, distance: u64) -> u128 {
+        let distance = distance as u128;
+        distance * distance
+    }
+
+    fn optimiser(&self) -> Optimiser {
+        Optimiser::MeanNeighbourhood
+    }
+}
+
+/// Any other cost, which had better be convex.
+impl<F: Fn(u64) -> u128> FuelCost for F {
+    fn cost(&self, distance: u64) -> u128 {
+        self(distance)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Alignment {
+    pub position: u64,
+    pub fuel: u128,
+}
+
+/// The fuel it takes every crab to get to `target`.
+pub fn fuel(positions: &[u64], cost: &impl FuelCost, target: u64) -> u128 {
+    positions
+        .iter()
+        .map(|p| cost.cost(p.abs_diff(target)))
+        .sum()
+}
+
+/// The cheapest of `candidates`, the first if there's a tie.
+fn cheapest_of(
+    positions: &[u64],
+    cost: &impl FuelCost,
+    candidates: impl IntoIterator<Item = u64>,
+) -> Alignment {
+    candidates
+        .into_iter()
+        .map(|position| Alignment {
+            position,
+            fuel: fuel(positions, cost, position),
+        })
+        .min_by_key(|alignment| (alignment.fuel, alignment.position))
+        .expect("there must be somewhere to go")
+}
+
+/// The cheapest position for every crab to move to, found with the cost's
+/// own optimiser.
+pub fn cheapest(positions: &[u64], cost: &impl FuelCost) -> Alignment {
+    cheapest_with(positions, cost, cost.optimiser())
+}
+
+pub fn cheapest_with(positions: &[u64], cost: &impl FuelCost, optimiser: Optimiser) -> Alignment {
+    assert!(!positions.is_empty(), "there are no crabs");
+
+    match optimiser {
+        Optimiser::Median => {
+            let mut sorted = positions.to_vec();
+            let middle = sorted.len() / 2;
+            let (_, &mut median, _) = sorted.select_nth_unstable(middle);
+            cheapest_of(positions, cost, [median])
+        }
+        Optimiser::MeanNeighbourhood => {
+            let sum: u128 = positions.iter().map(|&p| p as u128).sum();
+            let mean = (sum / positions.len() as u128) as u64;
+            cheapest_of(positions, cost, mean.saturating_sub(1)..=mean + 2)
+        }
+        Optimiser::TernarySearch => {
+            let mut low = *positions.iter().min().unwrap();
+            let mut high = *positions.iter().max().unwrap();
+
+            while high - low > 2 {
+                let third = (high - low) / 3;
+                let (a, b) = (low + third, high - third);
+                let (fuel_a, fuel_b) = (fuel(positions, cost, a), fuel(positions, cost, b));
+
+                // The total is convex, so the cheapest position can't be on
+                // the far side of the dearer of the two.
+                if fuel_a < fuel_b {
+                    high = b - 1;
+                } else if fuel_a > fuel_b {
+                    low = a + 1;
+                } else {
+                    (low, high) = (a, b);
+                }
+            }
+
+            cheapest_of(positions, cost, low..=high)
+        }
+    }
+}
+
+fn answer(alignment: Alignment) -> Answer {
+    Answer::Number(alignment.fuel.try_into().unwrap())
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::end(input, "a list of positions"))?;
+
+        line.text
+            .trim_end()
+            .split(',')
+            .map(|t| line.field(t, "a position"))
+            .collect()
+    }
+
+    fn part1(positions: &Self::Input) -> Answer {
+        answer(cheapest(positions, &Linear))
+    }
+
+    fn part2(positions: &Self::Input) -> Answer {
+        answer(cheapest(positions, &Triangular))
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day07::Day07>(env!("CARGO_MANIFEST_DIR"));
+}
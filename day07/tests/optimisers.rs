@@ -0,0 +1,63 @@
+use day07::{cheapest, cheapest_with, fuel, FuelCost, Linear, Optimiser, Quadratic, Triangular};
+
+fn crabs(count: usize, spread: u64) -> Vec<u64> {
+    let mut seed = 7u64;
+    (0..count)
+        .map(|_| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 20) % spread
+        })
+        .collect()
+}
+
+fn brute_force(positions: &[u64], cost: &impl FuelCost) -> u128 {
+    let (min, max) = (
+        *positions.iter().min().unwrap(),
+        *positions.iter().max().unwrap(),
+    );
+    (min..=max).map(|p| fuel(positions, cost, p)).min().unwrap()
+}
+
+#[test]
+fn every_optimiser_finds_the_cheapest_position() {
+    let cubic = |d: u64| (d as u128).pow(3);
+
+    for positions in [crabs(50, 100), crabs(51, 1000), vec![5], vec![1, 1000]] {
+        assert_eq!(
+            cheapest(&positions, &Linear).fuel,
+            brute_force(&positions, &Linear)
+        );
+        assert_eq!(
+            cheapest(&positions, &Triangular).fuel,
+            brute_force(&positions, &Triangular)
+        );
+        assert_eq!(
+            cheapest(&positions, &Quadratic).fuel,
+            brute_force(&positions, &Quadratic)
+        );
+        assert_eq!(
+            cheapest(&positions, &cubic).fuel,
+            brute_force(&positions, &cubic)
+        );
+
+        assert_eq!(
+            cheapest_with(&positions, &Triangular, Optimiser::TernarySearch).fuel,
+            brute_force(&positions, &Triangular)
+        );
+    }
+}
+
+#[test]
+fn lots_of_crabs_spread_far_apart() {
+    let positions = crabs(100_000, 1_000_000_000);
+
+    let linear = cheapest(&positions, &Linear);
+    let searched = cheapest_with(&positions, &Linear, Optimiser::TernarySearch);
+    assert_eq!(linear.fuel, searched.fuel);
+
+    let quadratic = cheapest(&positions, &Quadratic);
+    let searched = cheapest_with(&positions, &Quadratic, Optimiser::TernarySearch);
+    assert_eq!(quadratic.fuel, searched.fuel);
+}
@@ -0,0 +1,483 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use aoc_common::parse::{self, InputLine};
+use aoc_common::{Answer, ParseError, Solution};
+use serde::Serialize;
+
+/// A set of segments or wires, one bit each, with `a` as bit 0.
+pub type Segments = u32;
+
+/// The set of segments named in `names`, or the first name that isn't one of
+/// the first `segments` letters.
+fn segment_set(names: &str, segments: usize) -> Result<Segments, char> {
+    names
+        .chars()
+        .try_fold(0, |set, name| match (name as u32).checked_sub('a' as u32) {
+            Some(bit) if (bit as usize) < segments => Ok(set | 1 << bit),
+            _ => Err(name),
+        })
+}
+
+fn segment_names(set: Segments) -> String {
+    (0..26)
+        .filter(|&bit| set & 1 << bit != 0)
+        .map(|bit| (b'a' + bit as u8) as char)
+        .collect()
+}
+
+/// The symbols a display can show, and which segments each one lights.
+/// Segments are named with letters from `a`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Glyphs {
+    segments: usize,
+    glyphs: Vec<(char, Segments)>,
+}
+
+impl Glyphs {
+    pub fn new(segments: usize, glyphs: &[(char, &str)]) -> Result<Self, GlyphError> {
+        if !(1..=26).contains(&segments) {
+            return Err(GlyphError::Segments(segments));
+        }
+
+        let glyphs: Vec<(char, Segments)> = glyphs
+            .iter()
+            .map(|&(symbol, lit)| match segment_set(lit, segments) {
+                Ok(set) => Ok((symbol, set)),
+                Err(name) => Err(GlyphError::UnknownSegment(symbol, name)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        for (i, &(symbol, set)) in glyphs.iter().enumerate() {
+            if let Some(&(other, _)) = glyphs[..i].iter().find(|&&(_, s)| s == set) {
+                return Err(GlyphError::Lookalikes(other, symbol));
+            }
+        }
+
+        Ok(Self { segments, glyphs })
+    }
+
+    /// The digits 0 to 9 on a seven-segment display, with `a` at the top,
+    /// `b` and `c` the upper sides, `d` the middle, `e` and `f` the lower
+    /// sides and `g` the bottom.
+    pub fn digits() -> Self {
+        Self::new(7, &Self::DIGITS).unwrap()
+    }
+
+    /// The digits and A to F.
+    pub fn hexadecimal() -> Self {
+        let mut glyphs = Self::DIGITS.to_vec();
+        glyphs.extend([
+            ('A', "abcdef"),
+            ('b', "bdefg"),
+            ('C', "abeg"),
+            ('d', "cdefg"),
+            ('E', "abdeg"),
+            ('F', "abde"),
+        ]);
+        Self::new(7, &glyphs).unwrap()
+    }
+
+    const DIGITS: [(char, &'static str); 10] = [
+        ('0', "abcefg"),
+        ('1', "cf"),
+        ('2', "acdeg"),
+        ('3', "acdfg"),
+        ('4', "bcdf"),
+        ('5', "abdfg"),
+        ('6', "abdefg"),
+        ('7', "acf"),
+        ('8', "abcdefg"),
+        ('9', "abcdfg"),
+    ];
+
+    pub fn segments(&self) -> usize {
+        self.segments
+    }
+
+    /// The name of the last segment.
+    fn last(&self) -> char {
+        (b'a' + self.segments as u8 - 1) as char
+    }
+
+    pub fn symbol(&self, lit: Segments) -> Option<char> {
+        self.glyphs
+            .iter()
+            .find(|&&(_, set)| set == lit)
+            .map(|&(symbol, _)| symbol)
+    }
+}
+
+/// Why a set of glyphs couldn't be made.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlyphError {
+    /// Segments are named `a` to `z`, so there can be 1 to 26 of them.
+    Segments(usize),
+    /// A glyph lights something that isn't one of the segments.
+    UnknownSegment(char, char),
+    /// Two glyphs light the same segments.
+    Lookalikes(char, char),
+}
+
+impl Display for GlyphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Segments(n) => write!(f, "{} segments, not 1 to 26", n),
+            Self::UnknownSegment(symbol, name) => {
+                write!(f, "{:?} lights {:?}, which isn't a segment", symbol, name)
+            }
+            Self::Lookalikes(a, b) => write!(f, "{:?} and {:?} look the same", a, b),
+        }
+    }
+}
+
+impl std::error::Error for GlyphError {}
+
+/// Why an entry couldn't be decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// No wiring turns every signal and pattern into a glyph.
+    NoSolution,
+    /// More than one wiring does.
+    Ambiguous,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSolution => write!(f, "no wiring fits"),
+            Self::Ambiguous => write!(f, "more than one wiring fits"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decoded {
+    /// The segment each wire is connected to.
+    pub wiring: Vec<usize>,
+    /// What the patterns show.
+    pub symbols: String,
+}
+
+/// One display: the signals for every glyph it can show, in some order, and
+/// the patterns it's showing now. Both are sets of wires, which are
+/// connected to the segments in some unknown order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub signals: Vec<Segments>,
+    pub patterns: Vec<Segments>,
+}
+
+impl Entry {
+    /// Works out which wire goes to which segment, so that every signal and
+    /// pattern shows a glyph.
+    pub fn decode(&self, glyphs: &Glyphs) -> Result<Decoded, DecodeError> {
+        let wires = glyphs.segments();
+        let everything: Segments = (1 << wires) - 1;
+        let observed: Vec<Segments> = self.signals.iter().chain(&self.patterns).copied().collect();
+        if observed.iter().any(|&set| set & !everything != 0) {
+            return Err(DecodeError::NoSolution);
+        }
+
+        // A set of n wires lights one of the glyphs with n segments, so its
+        // wires can only go to segments in those glyphs, and the other wires
+        // can't go to segments that all of those glyphs light.
+        let mut domains = vec![everything; wires];
+        for &set in &observed {
+            let same_size = glyphs
+                .glyphs
+                .iter()
+                .map(|&(_, lit)| lit)
+                .filter(|lit| lit.count_ones() == set.count_ones());
+            let any = same_size.clone().fold(0, |a, lit| a | lit);
+            let all = same_size.fold(everything, |a, lit| a & lit);
+
+            for (wire, domain) in domains.iter_mut().enumerate() {
+                *domain &= if set & 1 << wire != 0 { any } else { !all };
+            }
+        }
+
+        let mut search = Search {
+            glyphs,
+            observed: &observed,
+            domains: &domains,
+            wiring: vec![None; wires],
+            solutions: Vec::new(),
+        };
+        search.assign(0);
+
+        match &search.solutions[..] {
+            [] => Err(DecodeError::NoSolution),
+            [wiring] => Ok(Decoded {
+                wiring: wiring.clone(),
+                symbols: self
+                    .patterns
+                    .iter()
+                    .map(|&set| glyphs.symbol(rewire(set, wiring)).unwrap())
+                    .collect(),
+            }),
+            _ => Err(DecodeError::Ambiguous),
+        }
+    }
+}
+
+/// The segments lit by the wires in `set`.
+fn rewire(set: Segments, wiring: &[usize]) -> Segments {
+    wiring
+        .iter()
+        .enumerate()
+        .filter(|&(wire, _)| set & 1 << wire != 0)
+        .fold(0, |lit, (_, &segment)| lit | 1 << segment)
+}
+
+/// A backtracking search for wirings, which stops once it finds two.
+struct Search<'a> {
+    glyphs: &'a Glyphs,
+    observed: &'a [Segments],
+    domains: &'a [Segments],
+    wiring: Vec<Option<usize>>,
+    solutions: Vec<Vec<usize>>,
+}
+
+impl Search<'_> {
+    fn assign(&mut self, used: Segments) {
+        if self.solutions.len() > 1 {
+            return;
+        }
+
+        // Try the wire with the fewest segments left to go to first.
+        let next = (0..self.wiring.len())
+            .filter(|&wire| self.wiring[wire].is_none())
+            .min_by_key(|&wire| (self.domains[wire] & !used).count_ones());
+        let wire = match next {
+            Some(wire) => wire,
+            None => {
+                self.solutions
+                    .push(self.wiring.iter().map(|s| s.unwrap()).collect());
+                return;
+            }
+        };
+
+        let candidates = self.domains[wire] & !used;
+        for segment in (0..self.glyphs.segments()).filter(|&s| candidates & 1 << s != 0) {
+            self.wiring[wire] = Some(segment);
+            if self.consistent() {
+                self.assign(used | 1 << segment);
+            }
+        }
+        self.wiring[wire] = None;
+    }
+
+    /// Whether every set could still light a glyph: the segments its wired
+    /// wires go to must all be lit by some glyph of the right size.
+    fn consistent(&self) -> bool {
+        self.observed.iter().all(|&set| {
+            let lit = self
+                .wiring
+                .iter()
+                .enumerate()
+                .filter(|&(wire, _)| set & 1 << wire != 0)
+                .filter_map(|(_, &segment)| segment)
+                .fold(0, |lit: Segments, segment| lit | 1 << segment);
+
+            self.glyphs
+                .glyphs
+                .iter()
+                .any(|&(_, glyph)| glyph.count_ones() == set.count_ones() && glyph & lit == lit)
+        })
+    }
+}
+
+/// What we could work out about one entry, for auditing displays that
+/// don't decode.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Diagnosis {
+    pub line: usize,
+    /// The segment each wire goes to.
+    pub wiring: Option<BTreeMap<char, char>>,
+    /// What the patterns show.
+    pub symbols: Option<String>,
+    /// Signals that none of the patterns use.
+    pub unused: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl Diagnosis {
+    fn new(line: usize, entry: &Entry, glyphs: &Glyphs) -> Self {
+        let unused = entry
+            .signals
+            .iter()
+            .filter(|signal| !entry.patterns.contains(signal))
+            .map(|&signal| segment_names(signal))
+            .collect();
+
+        match entry.decode(glyphs) {
+            Ok(decoded) => Self {
+                line,
+                wiring: Some(
+                    decoded
+                        .wiring
+                        .iter()
+                        .enumerate()
+                        .map(|(wire, &segment)| {
+                            ((b'a' + wire as u8) as char, (b'a' + segment as u8) as char)
+                        })
+                        .collect(),
+                ),
+                symbols: Some(decoded.symbols),
+                unused,
+                error: None,
+            },
+            Err(e) => Self {
+                line,
+                wiring: None,
+                symbols: None,
+                unused,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+/// Decodes every entry in `input` against `glyphs`, noting the ones that
+/// don't decode rather than stopping at them.
+pub fn diagnose(input: &str, glyphs: &Glyphs) -> Result<Vec<Diagnosis>, ParseError> {
+    parse::lines(input)
+        .map(|line| Ok(Diagnosis::new(line.number, &entry(&line, glyphs)?, glyphs)))
+        .collect()
+}
+
+/// A table of diagnoses, with the wiring as the segment each wire goes to.
+pub fn table(diagnoses: &[Diagnosis]) -> String {
+    let wiring: Vec<String> = diagnoses
+        .iter()
+        .map(|d| match &d.wiring {
+            Some(wiring) => wiring
+                .iter()
+                .map(|(wire, segment)| format!("{}→{}", wire, segment))
+                .collect::<Vec<_>>()
+                .join(" "),
+            None => "-".into(),
+        })
+        .collect();
+    let shows: Vec<&str> = diagnoses
+        .iter()
+        .map(|d| match (&d.symbols, &d.error) {
+            (Some(symbols), _) => symbols.as_str(),
+            (None, Some(error)) => error.as_str(),
+            (None, None) => "",
+        })
+        .collect();
+
+    let width = wiring
+        .iter()
+        .map(|w| w.chars().count())
+        .chain(["Wiring".len()])
+        .max()
+        .unwrap();
+    let shows_width = shows
+        .iter()
+        .map(|s| s.chars().count())
+        .chain(["Shows".len()])
+        .max()
+        .unwrap();
+
+    // Widths count chars, which the arrows and some glyphs need.
+    let mut table = format!(
+        "Line  {:width$}  {:shows_width$}  Unused\n",
+        "Wiring", "Shows"
+    );
+    for ((diagnosis, wiring), shows) in diagnoses.iter().zip(&wiring).zip(shows) {
+        table += &format!(
+            "{:4}  {:width$}  {:shows_width$}  {}\n",
+            diagnosis.line,
+            wiring,
+            shows,
+            diagnosis.unused.join(" ")
+        );
+    }
+    table
+}
+
+pub fn json(diagnoses: &[Diagnosis]) -> String {
+    serde_json::to_string_pretty(diagnoses).unwrap()
+}
+
+fn segments(line: &InputLine, sets: &str, glyphs: &Glyphs) -> Result<Vec<Segments>, ParseError> {
+    sets.split_whitespace()
+        .map(|set| {
+            segment_set(set, glyphs.segments()).map_err(|name| {
+                let at = &set[set.find(name).unwrap()..];
+                line.error(at, format!("a segment from a to {}", glyphs.last()))
+            })
+        })
+        .collect()
+}
+
+fn entry(line: &InputLine, glyphs: &Glyphs) -> Result<Entry, ParseError> {
+    let [signals, patterns] = line.split(" | ", "signals | patterns")?;
+    Ok(Entry {
+        signals: segments(line, signals, glyphs)?,
+        patterns: segments(line, patterns, glyphs)?,
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    /// Every entry, and what it decodes to as digits. An entry that doesn't
+    /// decode is a parse error for both parts, even though part 1 only
+    /// counts the patterns' segments.
+    type Input = Vec<(Entry, Decoded)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let digits = Glyphs::digits();
+        parse::lines(input)
+            .map(|line| {
+                let entry = entry(&line, &digits)?;
+                match entry.decode(&digits) {
+                    Ok(decoded) => Ok((entry, decoded)),
+                    Err(DecodeError::NoSolution) => {
+                        Err(line.error(line.text, "signals that can be wired up"))
+                    }
+                    Err(DecodeError::Ambiguous) => {
+                        Err(line.error(line.text, "signals with only one wiring"))
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn part1(entries: &Self::Input) -> Answer {
+        entries
+            .iter()
+            .flat_map(|(entry, _)| &entry.patterns)
+            .filter(|n| [2, 3, 4, 7].contains(&n.count_ones()))
+            .count()
+            .into()
+    }
+
+    fn part2(entries: &Self::Input) -> Answer {
+        entries
+            .iter()
+            .map(|(_, decoded)| {
+                decoded
+                    .symbols
+                    .chars()
+                    .filter_map(|c| c.to_digit(10))
+                    .fold(0, |number, digit| number * 10 + digit)
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn visualise(input: &str, part: u8) -> Result<Option<Answer>, ParseError> {
+        let answer = Self::solve(input, part)?;
+        if part == 2 {
+            print!("{}", table(&diagnose(input, &Glyphs::digits())?));
+        }
+        Ok(answer)
+    }
+}
@@ -0,0 +1,138 @@
+use aoc_common::Solution;
+use day08::{Day08, DecodeError, Entry, GlyphError, Glyphs, Segments};
+
+fn wires(text: &str) -> Vec<Segments> {
+    text.split_whitespace()
+        .map(|w| w.bytes().fold(0, |set, b| set | 1 << (b - b'a')))
+        .collect()
+}
+
+fn entry(signals: &str, patterns: &str) -> Entry {
+    Entry {
+        signals: wires(signals),
+        patterns: wires(patterns),
+    }
+}
+
+#[test]
+fn hexadecimal() {
+    // The digits and A to F, with each segment wired to the next wire along.
+    let scrambled = entry(
+        "abcdfg dg abdef abdeg cdeg abceg abcefg bdg abcdefg abcdeg \
+         bcdefg acefg abcf adefg abcef bcef",
+        "bcdefg acefg bcef bdg",
+    );
+
+    let decoded = scrambled.decode(&Glyphs::hexadecimal()).unwrap();
+    assert_eq!(decoded.symbols, "AbF7");
+    assert_eq!(decoded.wiring, vec![6, 0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn custom_font() {
+    let bars = Glyphs::new(3, &[('-', "a"), ('=', "ab"), ('≡', "abc")]).unwrap();
+    let decoded = entry("c bc abc", "bc c").decode(&bars).unwrap();
+    assert_eq!(decoded.symbols, "=-");
+    assert_eq!(decoded.wiring, vec![2, 1, 0]);
+}
+
+#[test]
+fn inconsistent() {
+    let digits = Glyphs::digits();
+    assert_eq!(
+        entry("ab", "ab").decode(&digits),
+        Err(DecodeError::Ambiguous)
+    );
+    assert_eq!(
+        entry("ab abc", "a").decode(&digits),
+        Err(DecodeError::NoSolution)
+    );
+    assert_eq!(
+        entry("ab cd", "ab").decode(&digits),
+        Err(DecodeError::NoSolution)
+    );
+}
+
+#[test]
+fn diagnoses() {
+    let input = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | \
+                 fdgacbe cefdb cefbgd gcbe\n\
+                 ab cd | ab\n";
+    let diagnoses = day08::diagnose(input, &Glyphs::digits()).unwrap();
+
+    assert_eq!(diagnoses[0].symbols.as_deref(), Some("8394"));
+    let wiring = diagnoses[0].wiring.as_ref().unwrap();
+    assert_eq!(wiring.values().collect::<String>(), "ecdafgb");
+    assert_eq!(diagnoses[0].unused[0], "be");
+
+    assert_eq!(diagnoses[1].line, 2);
+    assert_eq!(diagnoses[1].wiring, None);
+    assert_eq!(diagnoses[1].error.as_deref(), Some("no wiring fits"));
+    assert_eq!(diagnoses[1].unused, vec!["cd"]);
+}
+
+#[test]
+fn tables_line_up() {
+    let bars = Glyphs::new(3, &[('-', "a"), ('=', "ab"), ('≡', "abc")]).unwrap();
+    let diagnoses = day08::diagnose("a ab abc | abc\nab | ab\n", &bars).unwrap();
+    let table = day08::table(&diagnoses);
+
+    let lines: Vec<&str> = table.lines().collect();
+    let column = |line: &str, text: &str| line[..line.find(text).unwrap()].chars().count();
+
+    assert_eq!(column(lines[0], "Shows"), column(lines[1], "≡"));
+    assert_eq!(column(lines[0], "Shows"), column(lines[2], "more than"));
+    assert_eq!(column(lines[0], "Unused"), column(lines[1], "a ab"));
+}
+
+#[test]
+fn bad_glyphs() {
+    assert_eq!(Glyphs::new(27, &[]), Err(GlyphError::Segments(27)));
+    assert_eq!(
+        Glyphs::new(3, &[('x', "aD")]),
+        Err(GlyphError::UnknownSegment('x', 'D'))
+    );
+    assert_eq!(
+        Glyphs::new(3, &[('x', "ad")]),
+        Err(GlyphError::UnknownSegment('x', 'd'))
+    );
+    assert_eq!(
+        Glyphs::new(3, &[('-', "ab"), ('=', "ba")]),
+        Err(GlyphError::Lookalikes('-', '='))
+    );
+}
+
+#[test]
+fn wide_fonts() {
+    // Nine bars, each lighting one more segment than the last, with the
+    // wires in reverse.
+    let names = "abcdefghi";
+    let glyphs: Vec<(char, &str)> = ('1'..='9').zip((1..=9).map(|n| &names[..n])).collect();
+    let bars = Glyphs::new(9, &glyphs).unwrap();
+
+    let input = "i hi ghi fghi efghi defghi cdefghi bcdefghi abcdefghi | ghi i\n";
+    let diagnoses = day08::diagnose(input, &bars).unwrap();
+    assert_eq!(diagnoses[0].symbols.as_deref(), Some("31"));
+
+    let e = day08::diagnose("a ij | a\n", &bars).unwrap_err();
+    assert_eq!(
+        (e.column, e.expected.as_str()),
+        (4, "a segment from a to i")
+    );
+    let e = Day08::parse("a gh | a\n").unwrap_err();
+    assert_eq!(
+        (e.column, e.expected.as_str()),
+        (4, "a segment from a to g")
+    );
+}
+
+#[test]
+fn every_entry_must_decode() {
+    // Part 1 only counts segments, but an entry that doesn't decode still
+    // fails to parse.
+    let e = Day08::solve("ab cd | ab\n", 1).unwrap_err();
+    assert_eq!(
+        (e.line, e.expected.as_str()),
+        (1, "signals that can be wired up")
+    );
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day08::Day08>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,220 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use aoc_common::grid::Grid;
+use aoc_common::heatmap::Heatmap;
+use aoc_common::render::{Headless, Renderer};
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+/// Which cells count as next to each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, down, left and right.
+    Four,
+    /// The diagonals too.
+    Eight,
+}
+
+impl Connectivity {
+    fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Self::Four => &[(0, -1), (-1, 0), (1, 0), (0, 1)],
+            Self::Eight => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Basin {
+    pub id: usize,
+    pub size: usize,
+    /// The lowest point, the first in reading order if there's a tie.
+    pub low_point: (usize, usize),
+    /// The top left and bottom right corners of the smallest box that holds
+    /// the basin.
+    pub bounds: ((usize, usize), (usize, usize)),
+}
+
+/// Every basin in a heightmap, and which basin each cell is in. Cells as
+/// high as the walls aren't in any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Basins {
+    pub labels: Grid<Option<usize>>,
+    pub basins: Vec<Basin>,
+}
+
+impl Basins {
+    /// Labels the basins in `heights`, which are separated by cells at least
+    /// `wall` high.
+    pub fn label(heights: &Grid<u32>, connectivity: Connectivity, wall: u32) -> Self {
+        Self::label_with(heights, connectivity, wall, &mut Headless)
+    }
+
+    /// Like [`Basins::label`], handing `renderer` the map as each basin is
+    /// filled in.
+    pub fn label_with(
+        heights: &Grid<u32>,
+        connectivity: Connectivity,
+        wall: u32,
+        renderer: &mut impl Renderer<Basins>,
+    ) -> Self {
+        let mut map = Self {
+            labels: heights.map(|_| None),
+            basins: Vec::new(),
+        };
+        let mut queue = VecDeque::new();
+        renderer.begin(&map);
+
+        for start in heights.positions() {
+            if heights[start] >= wall || map.labels[start].is_some() {
+                continue;
+            }
+
+            let id = map.basins.len();
+            let mut basin = Basin {
+                id,
+                size: 0,
+                low_point: start,
+                bounds: (start, start),
+            };
+            map.labels[start] = Some(id);
+            queue.push_back(start);
+
+            while let Some(position) = queue.pop_front() {
+                basin.size += 1;
+                let (x, y) = position;
+                let ((left, top), (right, bottom)) = basin.bounds;
+                basin.bounds = ((left.min(x), top.min(y)), (right.max(x), bottom.max(y)));
+
+                let (lx, ly) = basin.low_point;
+                if (heights[position], y, x) < (heights[basin.low_point], ly, lx) {
+                    basin.low_point = position;
+                }
+
+                for &delta in connectivity.offsets() {
+                    if let Some(neighbour) = heights.offset(position, delta) {
+                        if heights[neighbour] < wall && map.labels[neighbour].is_none() {
+                            map.labels[neighbour] = Some(id);
+                            queue.push_back(neighbour);
+                        }
+                    }
+                }
+            }
+
+            map.basins.push(basin);
+            renderer.frame(&map);
+        }
+
+        renderer.finish(&map);
+        map
+    }
+
+    /// The labelled map, with walls at 0 and each basin a colour of its own.
+    pub fn heatmap(&self) -> Heatmap {
+        // Stepping by the golden ratio keeps neighbouring ids apart.
+        Heatmap::from_grid(&self.labels, |label| match label {
+            Some(id) => 0.2 + 0.8 * (*id as f64 * 0.618_033_988_75).fract(),
+            None => 0.0,
+        })
+    }
+}
+
+/// The labelled map, with each basin as a letter or digit (repeating every
+/// 62 basins) and walls as `#`.
+impl Display for Basins {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const SYMBOLS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        for row in self.labels.rows() {
+            let line: String = row
+                .iter()
+                .map(|label| match label {
+                    Some(id) => SYMBOLS[id % SYMBOLS.len()] as char,
+                    None => '#',
+                })
+                .collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the labelled map once every basin is found.
+struct Map;
+
+impl Renderer<Basins> for Map {
+    fn finish(&mut self, basins: &Basins) {
+        print!("{}", basins);
+    }
+}
+
+/// The product of the sizes of the three largest basins.
+fn largest(basins: &Basins) -> Answer {
+    let mut sizes: Vec<usize> = basins.basins.iter().map(|basin| basin.size).collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.iter().take(3).product::<usize>().into()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let heights = Grid::digits(parse::lines(input))?;
+        if heights.is_empty() {
+            return Err(ParseError::end(input, "a heightmap"));
+        }
+
+        Ok(heights)
+    }
+
+    fn visualise(input: &str, part: u8) -> Result<Option<Answer>, ParseError> {
+        if part != 2 {
+            return Self::solve(input, part);
+        }
+
+        let heights = Self::parse(input)?;
+        Ok(Some(largest(&Basins::label_with(
+            &heights,
+            Connectivity::Four,
+            9,
+            &mut Map,
+        ))))
+    }
+
+    fn heatmap(input: &str, part: u8) -> Result<Option<Heatmap>, ParseError> {
+        let heights = Self::parse(input)?;
+        Ok(match part {
+            1 => Some(Heatmap::from_grid(&heights, |&h| h as f64)),
+            2 => Some(Basins::label(&heights, Connectivity::Four, 9).heatmap()),
+            _ => None,
+        })
+    }
+
+    fn part1(field: &Self::Input) -> Answer {
+        field
+            .positions()
+            .filter(|&position| {
+                field
+                    .neighbours4(position)
+                    .all(|neighbour| field[neighbour] > field[position])
+            })
+            .map(|position| 1 + field[position])
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(field: &Self::Input) -> Answer {
+        largest(&Basins::label(field, Connectivity::Four, 9))
+    }
+}
@@ -0,0 +1,54 @@
+use aoc_common::grid::Grid;
+use aoc_common::parse;
+use aoc_common::Solution;
+use day09::{Basin, Basins, Connectivity, Day09};
+
+const EXAMPLE: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+fn heights() -> Grid<u32> {
+    Grid::digits(parse::lines(EXAMPLE)).unwrap()
+}
+
+#[test]
+fn four_connected() {
+    let basins = Basins::label(&heights(), Connectivity::Four, 9);
+    assert_eq!(basins.basins.len(), 4);
+    assert_eq!(
+        basins.basins[2],
+        Basin {
+            id: 2,
+            size: 14,
+            low_point: (2, 2),
+            bounds: ((0, 1), (5, 4)),
+        }
+    );
+    assert_eq!(basins.labels[(2, 2)], Some(2));
+    assert_eq!(basins.labels[(2, 0)], None);
+    assert_eq!(basins.to_string().lines().next(), Some("00###11111"));
+}
+
+#[test]
+fn eight_connected() {
+    // Every basin touches another at a corner.
+    let basins = Basins::label(&heights(), Connectivity::Eight, 9);
+    let sizes: Vec<usize> = basins.basins.iter().map(|b| b.size).collect();
+    assert_eq!(sizes, vec![35]);
+}
+
+#[test]
+fn lower_walls() {
+    let basins = Basins::label(&heights(), Connectivity::Four, 6);
+    let low_points: Vec<_> = basins.basins.iter().map(|b| b.low_point).collect();
+    assert_eq!(low_points, vec![(1, 0), (9, 0), (2, 2), (6, 4)]);
+}
+
+#[test]
+fn needs_a_heightmap() {
+    let e = Day09::parse("").unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (1, "a heightmap"));
+}
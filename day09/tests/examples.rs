@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day09::Day09>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,93 @@
+use aoc_common::parse::{self, InputLine};
+use aoc_common::{Answer, ParseError, Solution};
+
+const fn opener(c: char) -> char {
+    match c {
+        ')' => '(',
+        ']' => '[',
+        '}' => '{',
+        '>' => '<',
+        _ => panic!("eek"),
+    }
+}
+
+const fn corrupted_score(c: char) -> u64 {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!("eeeeeeek"),
+    }
+}
+
+const fn completion_score(c: char) -> u64 {
+    match c {
+        '(' => 1,
+        '[' => 2,
+        '{' => 3,
+        '<' => 4,
+        _ => panic!("eeeeeeek"),
+    }
+}
+
+pub enum LineStatus {
+    Corrupted(char),
+    Incomplete(Vec<char>),
+}
+
+fn check(line: InputLine) -> Result<LineStatus, ParseError> {
+    let mut stack = Vec::new();
+    for (i, c) in line.text.char_indices() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            ')' | ']' | '}' | '>' => {
+                if stack.pop() != Some(opener(c)) {
+                    return Ok(LineStatus::Corrupted(c));
+                }
+            }
+            _ => return Err(line.error(&line.text[i..], "a bracket")),
+        }
+    }
+
+    Ok(LineStatus::Incomplete(stack))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<LineStatus>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input).map(check).collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .filter_map(|line| match line {
+                LineStatus::Corrupted(c) => Some(corrupted_score(*c)),
+                LineStatus::Incomplete(_) => None,
+            })
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        let mut scores = Vec::new();
+
+        for line in lines {
+            if let LineStatus::Incomplete(stack) = line {
+                let line_score = stack
+                    .iter()
+                    .rev()
+                    .fold(0, |score, &c| score * 5 + completion_score(c));
+                scores.push(line_score);
+            }
+        }
+
+        // With nothing to complete there's no middle score, so call it 0.
+        scores.sort_unstable();
+        scores.get(scores.len() / 2).copied().unwrap_or(0).into()
+    }
+}
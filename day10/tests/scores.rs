@@ -0,0 +1,13 @@
+use aoc_common::Solution;
+use day10::Day10;
+
+#[test]
+fn nothing_to_complete() {
+    let input = Day10::parse("").unwrap();
+    assert_eq!(Day10::part1(&input), 0.into());
+    assert_eq!(Day10::part2(&input), 0.into());
+
+    let input = Day10::parse("{([(<{}[<>[]}>{[]{[(<()>\n").unwrap();
+    assert_eq!(Day10::part1(&input), 1197.into());
+    assert_eq!(Day10::part2(&input), 0.into());
+}
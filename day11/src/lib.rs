@@ -0,0 +1,174 @@
+use aoc_common::automaton::{Automaton, Boundary, Rule, View};
+use aoc_common::grid::Grid;
+use aoc_common::render::{Headless, Renderer};
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Octopus {
+    energy: u32,
+    flashed: bool,
+}
+
+impl Octopus {
+    /// Whether the octopus has enough energy to flash but hasn't yet.
+    fn ready(&self) -> bool {
+        self.energy > 9 && !self.flashed
+    }
+}
+
+/// Every octopus gains a unit of energy, then the ones with enough flash,
+/// giving their neighbours a unit each and maybe making them flash too, and
+/// finally the ones that flashed go back to 0.
+struct Flashing;
+
+impl Rule<Octopus> for Flashing {
+    const PHASES: usize = 3;
+
+    fn settles(&self, phase: usize) -> bool {
+        phase == 1
+    }
+
+    fn next(&self, phase: usize, view: &View<Octopus>) -> Octopus {
+        let o = *view.cell();
+        match phase {
+            0 => Octopus {
+                energy: o.energy + 1,
+                flashed: false,
+            },
+            1 if o.ready() => Octopus { flashed: true, ..o },
+            1 if !o.flashed => Octopus {
+                energy: o.energy + view.neighbours8().filter(|n| n.ready()).count() as u32,
+                ..o
+            },
+            2 if o.flashed => Octopus { energy: 0, ..o },
+            _ => o,
+        }
+    }
+}
+
+type Field = Automaton<Octopus>;
+
+fn field(octopuses: &Grid<u32>) -> Field {
+    let octopuses = octopuses.map(|&energy| Octopus {
+        energy,
+        flashed: false,
+    });
+    Automaton::new(octopuses, Boundary::Finite)
+}
+
+fn flashes(field: &Field) -> usize {
+    field.grid().iter().filter(|o| o.flashed).count()
+}
+
+/// Prints the octopuses every ten steps, with the ones that just flashed
+/// highlighted.
+struct Terminal(Box<term::StdoutTerminal>);
+
+impl Terminal {
+    fn new() -> Self {
+        Self(term::stdout().unwrap())
+    }
+
+    fn print(&mut self, field: &Field) {
+        if field.generation() == 0 {
+            println!("Before any steps:");
+        } else {
+            println!("After step {}:", field.generation());
+        }
+
+        for row in field.grid().rows() {
+            for o in row {
+                if o.flashed {
+                    self.0.fg(term::color::WHITE).unwrap();
+                }
+                print!("{}", o.energy);
+                if o.flashed {
+                    self.0.reset().unwrap();
+                }
+            }
+            println!();
+        }
+        println!();
+    }
+}
+
+impl Renderer<Field> for Terminal {
+    fn begin(&mut self, field: &Field) {
+        self.print(field);
+    }
+
+    fn frame(&mut self, field: &Field) {
+        if field.generation().is_multiple_of(10) {
+            self.print(field);
+        }
+    }
+
+    fn finish(&mut self, field: &Field) {
+        if !field.generation().is_multiple_of(10) {
+            self.print(field);
+        }
+    }
+}
+
+fn total_flashes(octopuses: &Grid<u32>, renderer: &mut impl Renderer<Field>) -> usize {
+    let mut field = field(octopuses);
+    let mut total_flashes = 0;
+    renderer.begin(&field);
+
+    for _ in 0..100 {
+        field.step(&Flashing);
+        total_flashes += flashes(&field);
+        renderer.frame(&field);
+    }
+
+    renderer.finish(&field);
+    total_flashes
+}
+
+fn first_synchronised_step(octopuses: &Grid<u32>, renderer: &mut impl Renderer<Field>) -> usize {
+    let mut field = field(octopuses);
+    renderer.begin(&field);
+
+    loop {
+        field.step(&Flashing);
+        if flashes(&field) == field.grid().width() * field.grid().height() {
+            break;
+        }
+        renderer.frame(&field);
+    }
+
+    renderer.finish(&field);
+    field.generation()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let octopuses = Grid::digits(parse::lines(input))?;
+        if octopuses.is_empty() {
+            return Err(ParseError::end(input, "a grid of octopuses"));
+        }
+
+        Ok(octopuses)
+    }
+
+    fn part1(octopuses: &Self::Input) -> Answer {
+        total_flashes(octopuses, &mut Headless).into()
+    }
+
+    fn part2(octopuses: &Self::Input) -> Answer {
+        first_synchronised_step(octopuses, &mut Headless).into()
+    }
+
+    fn visualise(input: &str, part: u8) -> Result<Option<Answer>, ParseError> {
+        let octopuses = Self::parse(input)?;
+        Ok(match part {
+            1 => Some(total_flashes(&octopuses, &mut Terminal::new()).into()),
+            2 => Some(first_synchronised_step(&octopuses, &mut Terminal::new()).into()),
+            _ => None,
+        })
+    }
+}
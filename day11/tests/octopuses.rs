@@ -0,0 +1,8 @@
+use aoc_common::Solution;
+use day11::Day11;
+
+#[test]
+fn needs_octopuses() {
+    let e = Day11::parse("").unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (1, "a grid of octopuses"));
+}
@@ -1,11 +1,9 @@
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
-use itertools::Itertools;
+use aoc_common::{parse, Answer, ParseError, Solution};
 
-#[derive(Debug)]
-struct Cave {
+#[derive(Clone, Debug)]
+pub struct Cave {
     name: String,
     big: bool,
     connections: HashSet<String>,
@@ -25,13 +23,14 @@ impl Cave {
 
 struct Pathfinder {
     caves: HashMap<String, Cave>,
+    allow_revisit: bool,
     visited_small: HashSet<String>,
     visited_small_extra: Option<String>,
     stack: Vec<(String, Vec<String>)>,
 }
 
 impl Pathfinder {
-    fn new(caves: HashMap<String, Cave>) -> Self {
+    fn new(caves: HashMap<String, Cave>, allow_revisit: bool) -> Self {
         let start = caves.get("start").unwrap();
         let visited_small = HashSet::from([start.name.clone()]);
         let stack = vec![(
@@ -41,6 +40,7 @@ impl Pathfinder {
 
         Self {
             caves,
+            allow_revisit,
             visited_small,
             visited_small_extra: None,
             stack,
@@ -76,7 +76,7 @@ impl Iterator for Pathfinder {
 
             if !next_cave.big {
                 if self.visited_small.contains(&next_cave.name) {
-                    if self.visited_small_extra.is_some() {
+                    if !self.allow_revisit || self.visited_small_extra.is_some() {
                         continue;
                     } else {
                         self.visited_small_extra = Some(next_cave.name.clone());
@@ -96,38 +96,44 @@ impl Iterator for Pathfinder {
     }
 }
 
-fn main() {
-    let file = File::open(std::env::args_os().nth(1).unwrap()).unwrap();
-    let reader = BufReader::new(file);
+pub struct Day12;
 
-    let mut caves = HashMap::new();
+impl Solution for Day12 {
+    type Input = HashMap<String, Cave>;
 
-    for line in reader.lines() {
-        let line = line.unwrap();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut caves = HashMap::new();
 
-        let (first, second): (&str, &str) = line.split_terminator('-').collect_tuple().unwrap();
-        let first = first.to_string();
-        let second = second.to_string();
+        for line in parse::lines(input) {
+            let [first, second] = line.split("-", "a connection like start-A")?;
+            let first = first.to_string();
+            let second = second.to_string();
 
-        let first_cave = caves
-            .entry(first.clone())
-            .or_insert_with(|| Cave::new(&first));
-        first_cave.connections.insert(second.clone());
+            let first_cave = caves
+                .entry(first.clone())
+                .or_insert_with(|| Cave::new(&first));
+            first_cave.connections.insert(second.clone());
 
-        let second_cave = caves
-            .entry(second.clone())
-            .or_insert_with(|| Cave::new(&second));
-        second_cave.connections.insert(first.clone());
-    }
+            let second_cave = caves
+                .entry(second.clone())
+                .or_insert_with(|| Cave::new(&second));
+            second_cave.connections.insert(first.clone());
+        }
 
-    dbg!(&caves);
+        for name in ["start", "end"] {
+            if !caves.contains_key(name) {
+                return Err(ParseError::end(input, format!("a cave called {}", name)));
+            }
+        }
 
-    let mut counter = 0;
+        Ok(caves)
+    }
 
-    for path in Pathfinder::new(caves) {
-        println!("{}", path);
-        counter += 1;
+    fn part1(caves: &Self::Input) -> Answer {
+        Pathfinder::new(caves.clone(), false).count().into()
     }
 
-    println!("{} paths", counter);
+    fn part2(caves: &Self::Input) -> Answer {
+        Pathfinder::new(caves.clone(), true).count().into()
+    }
 }
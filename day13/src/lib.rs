@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub enum Fold {
+    Horizontal(u32),
+    Vertical(u32),
+}
+
+impl Fold {
+    /// Where `point` ends up, or `None` if folding would put it off the top
+    /// or left edge of the paper.
+    fn translate(&self, point: (u32, u32)) -> Option<(u32, u32)> {
+        match *self {
+            Self::Horizontal(y) if point.1 > y => Some((point.0, y.checked_sub(point.1 - y)?)),
+            Self::Vertical(x) if point.0 > x => Some((x.checked_sub(point.0 - x)?, point.1)),
+            _ => Some(point),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TransparentPaper(HashSet<(u32, u32)>);
+
+impl TransparentPaper {
+    fn fold(&mut self, fold: &Fold) -> Option<()> {
+        self.0 = self
+            .0
+            .iter()
+            .map(|&p| fold.translate(p))
+            .collect::<Option<_>>()?;
+        Some(())
+    }
+
+    fn dot_count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl std::fmt::Display for TransparentPaper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.0.iter().map(|(x, _)| x + 1).max().unwrap_or(0);
+        let height = self.0.iter().map(|(_, y)| y + 1).max().unwrap_or(0);
+
+        for y in 0..height {
+            for x in 0..width {
+                if self.0.contains(&(x, y)) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (TransparentPaper, Vec<Fold>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut points = HashSet::new();
+        let mut folded = TransparentPaper(HashSet::new());
+        let mut folds = Vec::new();
+        let mut reading_points = true;
+
+        for line in parse::lines(input) {
+            if line.text.is_empty() {
+                reading_points = false;
+                folded = TransparentPaper(points.clone());
+                continue;
+            }
+
+            if reading_points {
+                let [x, y] = line.split(",", "a point like 6,10")?;
+                points.insert((
+                    line.field(x, "a coordinate")?,
+                    line.field(y, "a coordinate")?,
+                ));
+            } else {
+                let [axis, position] = line.split("=", "a fold like fold along y=7")?;
+                let value = line.field(position, "a fold position")?;
+                let fold = match axis {
+                    "fold along x" => Fold::Vertical(value),
+                    "fold along y" => Fold::Horizontal(value),
+                    _ => return Err(line.error(axis, "fold along x or fold along y")),
+                };
+                // Check every dot lands on the paper now, so the parts don't
+                // have to.
+                folded
+                    .fold(&fold)
+                    .ok_or_else(|| line.error(position, "a fold at least halfway across"))?;
+                folds.push(fold);
+            }
+        }
+
+        if folds.is_empty() {
+            return Err(ParseError::end(input, "a fold like fold along y=7"));
+        }
+
+        Ok((TransparentPaper(points), folds))
+    }
+
+    fn part1((paper, folds): &Self::Input) -> Answer {
+        let mut paper = paper.clone();
+        paper.fold(&folds[0]).unwrap();
+        paper.dot_count().into()
+    }
+
+    fn part2((paper, folds): &Self::Input) -> Answer {
+        let mut paper = paper.clone();
+        for fold in folds {
+            paper.fold(fold).unwrap();
+        }
+        paper.to_string().into()
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day13::Day13>(env!("CARGO_MANIFEST_DIR"));
+}
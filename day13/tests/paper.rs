@@ -0,0 +1,35 @@
+use aoc_common::Solution;
+use day13::Day13;
+
+#[test]
+fn needs_a_fold() {
+    let e = Day13::parse("").unwrap_err();
+    assert_eq!(
+        (e.line, e.expected.as_str()),
+        (1, "a fold like fold along y=7")
+    );
+
+    let e = Day13::parse("6,10\n0,14\n").unwrap_err();
+    assert_eq!(
+        (e.line, e.expected.as_str()),
+        (3, "a fold like fold along y=7")
+    );
+}
+
+#[test]
+fn folds_stay_on_the_paper() {
+    let e = Day13::parse("6,10\n0,14\n\nfold along y=4\n").unwrap_err();
+    assert_eq!((e.line, e.column), (4, 14));
+
+    // 9,0 lands on 3,0 after the first fold, too far out for the second.
+    let e = Day13::parse("9,0\n\nfold along x=6\nfold along x=1\n").unwrap_err();
+    assert_eq!(e.line, 4);
+    assert_eq!(e.expected, "a fold at least halfway across");
+}
+
+#[test]
+fn blank_paper() {
+    let input = Day13::parse("\nfold along y=7\n").unwrap();
+    assert_eq!(Day13::part1(&input), 0.into());
+    assert_eq!(Day13::part2(&input), "".into());
+}
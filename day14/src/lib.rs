@@ -0,0 +1,110 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_common::{parse, Answer, ParseError, Solution};
+use itertools::{Itertools, MinMaxResult};
+
+#[derive(Clone, Debug)]
+pub struct Polymeriserator {
+    state: HashMap<(char, char), usize>,
+    rules: HashMap<(char, char), char>,
+    counts: HashMap<char, usize>,
+}
+
+impl Polymeriserator {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let mut lines = parse::lines(input);
+        let template = lines
+            .next()
+            .ok_or_else(|| ParseError::end(input, "a polymer template"))?
+            .text;
+        match lines.next() {
+            Some(line) if line.text.is_empty() => (),
+            Some(line) => return Err(line.error(line.text, "a blank line")),
+            None => return Err(ParseError::end(input, "a blank line")),
+        }
+
+        let counts = template.chars().counts();
+        let state = template.chars().tuple_windows::<(char, char)>().counts();
+
+        let rules = lines
+            .map(|line| {
+                let [pair, insert] = line.split(" -> ", "a rule like CH -> B")?;
+                let pair = pair
+                    .chars()
+                    .collect_tuple()
+                    .ok_or_else(|| line.error(pair, "a pair of elements"))?;
+                let insert = insert
+                    .chars()
+                    .exactly_one()
+                    .map_err(|_| line.error(insert, "a single element"))?;
+                Ok((pair, insert))
+            })
+            .collect::<Result<HashMap<(char, char), char>, ParseError>>()?;
+
+        // Every pair that can turn up needs a rule: the template's, and the
+        // ones the rules make from them.
+        let mut pairs: VecDeque<(char, char)> = template.chars().tuple_windows().collect();
+        let mut seen = HashSet::new();
+        while let Some((a, b)) = pairs.pop_front() {
+            if !seen.insert((a, b)) {
+                continue;
+            }
+            let insert = *rules
+                .get(&(a, b))
+                .ok_or_else(|| ParseError::end(input, format!("a rule for {}{}", a, b)))?;
+            pairs.extend([(a, insert), (insert, b)]);
+        }
+
+        Ok(Self {
+            state,
+            rules,
+            counts,
+        })
+    }
+
+    fn step(&mut self) {
+        let mut new_state = HashMap::new();
+        for (&(a, b), &count) in &self.state {
+            let insert = self.rules[&(a, b)];
+
+            *new_state.entry((a, insert)).or_insert(0) += count;
+            *new_state.entry((insert, b)).or_insert(0) += count;
+            *self.counts.entry(insert).or_insert(0) += count;
+        }
+        self.state = new_state;
+    }
+
+    fn value(&self) -> usize {
+        if let MinMaxResult::MinMax(min, max) = self.counts.values().minmax() {
+            max - min
+        } else {
+            0
+        }
+    }
+}
+
+fn polymerise(poly: &Polymeriserator, steps: usize) -> usize {
+    let mut poly = poly.clone();
+    for _ in 0..steps {
+        poly.step();
+    }
+    poly.value()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Polymeriserator;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Polymeriserator::from_input(input)
+    }
+
+    fn part1(poly: &Self::Input) -> Answer {
+        polymerise(poly, 10).into()
+    }
+
+    fn part2(poly: &Self::Input) -> Answer {
+        polymerise(poly, 40).into()
+    }
+}
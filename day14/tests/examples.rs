@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day14::Day14>(env!("CARGO_MANIFEST_DIR"));
+}
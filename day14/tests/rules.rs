@@ -0,0 +1,12 @@
+use aoc_common::Solution;
+use day14::Day14;
+
+#[test]
+fn missing_rules() {
+    let e = Day14::parse("NNCB\n\nCH -> B\n").unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (4, "a rule for NN"));
+
+    // CB is covered, but the CN it makes isn't.
+    let e = Day14::parse("CB\n\nCB -> N\nNB -> N\nNN -> N\n").unwrap_err();
+    assert_eq!(e.expected, "a rule for CN");
+}
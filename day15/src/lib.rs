@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+use std::io::{stdout, Write};
+
+use aoc_common::grid::Grid;
+use aoc_common::heatmap::Heatmap;
+use aoc_common::render::{Headless, Renderer};
+use aoc_common::search::{self, Progress};
+use aoc_common::{parse, Answer, ParseError, Solution};
+use crossterm::{cursor, style, style::Stylize, terminal, ExecutableCommand, QueueableCommand};
+
+/// The lowest total risk of any path from the top left of the cavern to the
+/// bottom right.
+fn lowest_risk(
+    cavern: &Grid<usize>,
+    renderer: &mut impl for<'a> Renderer<Progress<'a, (usize, usize)>>,
+) -> usize {
+    let exit = (cavern.width() - 1, cavern.height() - 1);
+
+    search::astar(
+        (0, 0),
+        |&position| {
+            cavern
+                .neighbours4(position)
+                .map(|neighbour| (neighbour, cavern[neighbour]))
+                .collect::<Vec<_>>()
+        },
+        |&(x, y)| (exit.0 - x) + (exit.1 - y),
+        |&position| position == exit,
+        renderer,
+    )
+    .unwrap()
+    .cost
+}
+
+/// Draws the cavern in the alternate screen, highlighting the path being
+/// followed, and waits for a keypress once the exit is found.
+struct Terminal<'a> {
+    cavern: &'a Grid<usize>,
+    stdout: std::io::Stdout,
+    previous_path: HashSet<(usize, usize)>,
+}
+
+impl<'a> Terminal<'a> {
+    fn new(cavern: &'a Grid<usize>) -> Self {
+        let mut stdout = stdout();
+        stdout.execute(terminal::EnterAlternateScreen).unwrap();
+        stdout.execute(cursor::Hide).unwrap();
+
+        Self {
+            cavern,
+            stdout,
+            previous_path: HashSet::new(),
+        }
+    }
+}
+
+impl Renderer<Progress<'_, (usize, usize)>> for Terminal<'_> {
+    fn begin(&mut self, _progress: &Progress<(usize, usize)>) {
+        let cavern = self.cavern;
+
+        for (y, row) in cavern.rows().enumerate() {
+            self.stdout.queue(cursor::MoveTo(0, y as u16)).unwrap();
+            for &v in row {
+                self.stdout
+                    .queue(style::PrintStyledContent(format!("{} ", v).reset()))
+                    .unwrap();
+            }
+        }
+
+        self.stdout
+            .queue(cursor::MoveTo(0, cavern.height() as u16 + 1))
+            .unwrap();
+        self.stdout
+            .queue(style::PrintStyledContent(
+                format!("Current Score: {:4}", 0).reset(),
+            ))
+            .unwrap();
+
+        self.stdout.flush().unwrap();
+    }
+
+    fn frame(&mut self, progress: &Progress<(usize, usize)>) {
+        let cavern = self.cavern;
+        let path = HashSet::from_iter(progress.path());
+
+        for &(x, y) in self.previous_path.difference(&path) {
+            self.stdout
+                .queue(cursor::MoveTo(x as u16 * 2, y as u16))
+                .unwrap();
+            self.stdout
+                .queue(style::PrintStyledContent(
+                    format!("{} ", cavern[(x, y)]).reset(),
+                ))
+                .unwrap();
+        }
+
+        for &(x, y) in &path {
+            self.stdout
+                .queue(cursor::MoveTo(x as u16 * 2, y as u16))
+                .unwrap();
+            self.stdout
+                .queue(style::PrintStyledContent(
+                    format!("{} ", cavern[(x, y)]).green().bold(),
+                ))
+                .unwrap();
+        }
+
+        self.stdout
+            .queue(cursor::MoveTo(0, cavern.height() as u16 + 1))
+            .unwrap();
+        self.stdout
+            .queue(style::PrintStyledContent(
+                format!("Current Score: {:4}", progress.cost).reset(),
+            ))
+            .unwrap();
+
+        self.previous_path = path;
+
+        self.stdout.flush().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    fn finish(&mut self, progress: &Progress<(usize, usize)>) {
+        self.frame(progress);
+        crossterm::event::read().unwrap();
+    }
+}
+
+impl Drop for Terminal<'_> {
+    fn drop(&mut self) {
+        self.stdout.execute(cursor::Show).unwrap();
+        self.stdout.execute(terminal::LeaveAlternateScreen).unwrap();
+    }
+}
+
+/// Tiles the cavern five times in each direction, each tile one riskier than
+/// the last.
+fn enlarge(cavern: &Grid<usize>) -> Grid<usize> {
+    let (width, height) = (cavern.width(), cavern.height());
+    let mut bigger_cavern = Grid::new(width * 5, height * 5, 0);
+
+    for (x, y) in bigger_cavern.positions().collect::<Vec<_>>() {
+        let risk = cavern[(x % width, y % height)] + x / width + y / height;
+        bigger_cavern[(x, y)] = (risk - 1) % 9 + 1;
+    }
+
+    bigger_cavern
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let cavern = Grid::digits(parse::lines(input))?;
+        if cavern.is_empty() {
+            return Err(ParseError::end(input, "a cavern"));
+        }
+
+        Ok(cavern.map(|&d| d as usize))
+    }
+
+    fn part1(cavern: &Self::Input) -> Answer {
+        lowest_risk(cavern, &mut Headless).into()
+    }
+
+    fn part2(cavern: &Self::Input) -> Answer {
+        lowest_risk(&enlarge(cavern), &mut Headless).into()
+    }
+
+    fn visualise(input: &str, part: u8) -> Result<Option<Answer>, ParseError> {
+        let cavern = Self::parse(input)?;
+        let cavern = match part {
+            1 => cavern,
+            2 => enlarge(&cavern),
+            _ => return Ok(None),
+        };
+
+        let mut terminal = Terminal::new(&cavern);
+        Ok(Some(lowest_risk(&cavern, &mut terminal).into()))
+    }
+
+    fn heatmap(input: &str, part: u8) -> Result<Option<Heatmap>, ParseError> {
+        let cavern = Self::parse(input)?;
+        let cavern = match part {
+            1 => cavern,
+            2 => enlarge(&cavern),
+            _ => return Ok(None),
+        };
+        Ok(Some(Heatmap::from_grid(&cavern, |&risk| risk as f64)))
+    }
+}
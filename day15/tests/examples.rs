@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day15::Day15>(env!("CARGO_MANIFEST_DIR"));
+}
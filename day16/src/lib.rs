@@ -0,0 +1,269 @@
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug)]
+enum Operator {
+    Sum = 0,
+    Product = 1,
+    Minimum = 2,
+    Maximum = 3,
+    GreaterThan = 5,
+    LessThan = 6,
+    EqualTo = 7,
+}
+
+impl TryFrom<u8> for Operator {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Sum,
+            1 => Self::Product,
+            2 => Self::Minimum,
+            3 => Self::Maximum,
+            5 => Self::GreaterThan,
+            6 => Self::LessThan,
+            7 => Self::EqualTo,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl Operator {
+    /// Whether `count` sub-packets are enough, and the right number for
+    /// comparisons.
+    fn takes(&self, count: usize) -> bool {
+        match self {
+            Self::GreaterThan | Self::LessThan | Self::EqualTo => count == 2,
+            _ => count > 0,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::Sum => "a sum packet with at least one sub-packet",
+            Self::Product => "a product packet with at least one sub-packet",
+            Self::Minimum => "a minimum packet with at least one sub-packet",
+            Self::Maximum => "a maximum packet with at least one sub-packet",
+            Self::GreaterThan => "a greater-than packet with two sub-packets",
+            Self::LessThan => "a less-than packet with two sub-packets",
+            Self::EqualTo => "an equal-to packet with two sub-packets",
+        }
+    }
+}
+
+/// Why a packet couldn't be read.
+#[derive(Debug)]
+enum Malformed {
+    /// The data ends partway through it.
+    Truncated,
+    /// An operator has the wrong number of sub-packets.
+    Operands(Operator),
+}
+
+#[derive(Debug)]
+enum PacketBody {
+    Literal(usize),
+    Operator(Operator, Vec<Packet>),
+}
+
+trait Resync {
+    type Output;
+
+    fn resync(&self, shift: usize) -> Self::Output;
+}
+
+impl<A: AsRef<[u8]>> Resync for A {
+    type Output = Vec<u8>;
+
+    fn resync(&self, shift: usize) -> Self::Output {
+        let arr = self.as_ref();
+
+        let mut shift = shift;
+        let mut skip = 0;
+
+        while shift >= 8 {
+            skip += 1;
+            shift -= 8;
+        }
+
+        if shift == 0 {
+            return arr.get(skip..).unwrap_or_default().to_vec();
+        }
+
+        let mut resynced = Vec::new();
+        let mut byte = 0;
+        let mask = !((1 << shift) - 1);
+
+        for &b in arr.iter().skip(skip) {
+            byte |= (b >> (8 - shift)) & !mask;
+            resynced.push(byte);
+            byte = (b << shift) & mask;
+        }
+
+        resynced.push(byte);
+        resynced.remove(0);
+
+        resynced
+    }
+}
+
+impl PacketBody {
+    fn parse_literal(data: &[u8]) -> Option<(Self, usize)> {
+        let mut data = data.resync(6);
+        let mut number = 0;
+        let mut bits = 6;
+
+        loop {
+            let first = *data.first()?;
+            let last = first & 0x80 == 0;
+            number <<= 4;
+            number |= (first as usize >> 3) & 0x0f;
+            bits += 5;
+
+            if last {
+                return Some((Self::Literal(number), bits));
+            }
+
+            data = data.resync(5);
+        }
+    }
+
+    fn parse_15(operator: Operator, data: &[u8]) -> Result<(PacketBody, usize), Malformed> {
+        let data = data.resync(7);
+
+        let (first, second) = match data[..] {
+            [first, second, ..] => (first as usize, second as usize),
+            _ => return Err(Malformed::Truncated),
+        };
+        let bits = first << 7 | second >> 1;
+
+        let mut data = data.resync(15);
+        let mut used = 0;
+        let mut packets = Vec::new();
+
+        while used < bits {
+            let (packet, packet_bits) = Packet::parse_bytes(&data)?;
+            packets.push(packet);
+            data = data.resync(packet_bits);
+            used += packet_bits;
+        }
+
+        Ok((PacketBody::Operator(operator, packets), 7 + 15 + bits))
+    }
+
+    fn parse_11(operator: Operator, data: &[u8]) -> Result<(PacketBody, usize), Malformed> {
+        let data = data.resync(7);
+
+        let mut bits = 18;
+        let npackets = match data[..] {
+            [first, second, ..] => (first as usize) << 3 | (second as usize) >> 5,
+            _ => return Err(Malformed::Truncated),
+        };
+        let mut data = data.resync(11);
+        let mut packets = Vec::new();
+
+        for _ in 0..npackets {
+            let (packet, packet_bits) = Packet::parse_bytes(&data)?;
+            packets.push(packet);
+            data = data.resync(packet_bits);
+            bits += packet_bits;
+        }
+
+        Ok((PacketBody::Operator(operator, packets), bits))
+    }
+}
+
+#[derive(Debug)]
+pub struct Packet {
+    version: u8,
+    body: PacketBody,
+}
+
+impl Packet {
+    /// Parses the packet at the start of `data`, returning it and the number
+    /// of bits it took up.
+    fn parse_bytes(data: &[u8]) -> Result<(Self, usize), Malformed> {
+        let byte = *data.first().ok_or(Malformed::Truncated)?;
+        let version = (byte >> 5) & 0x7;
+        let packet_type = (byte >> 2) & 0x7;
+
+        let (body, bits) = match Operator::try_from(packet_type) {
+            Err(_) => PacketBody::parse_literal(data).ok_or(Malformed::Truncated)?,
+            Ok(operator) if byte & 0x2 == 0 => PacketBody::parse_15(operator, data)?,
+            Ok(operator) => PacketBody::parse_11(operator, data)?,
+        };
+
+        if let PacketBody::Operator(operator, packets) = &body {
+            if !operator.takes(packets.len()) {
+                return Err(Malformed::Operands(*operator));
+            }
+        }
+
+        Ok((Self { version, body }, bits))
+    }
+
+    fn version_sum(&self) -> usize {
+        let version = self.version as usize;
+        let sub_version = if let PacketBody::Operator(_, packets) = &self.body {
+            packets.iter().map(|p| p.version_sum()).sum::<usize>()
+        } else {
+            0
+        };
+
+        version + sub_version
+    }
+
+    fn evaluate(&self) -> usize {
+        let (operator, packets) = match &self.body {
+            PacketBody::Literal(value) => return *value,
+            PacketBody::Operator(operator, packets) => (operator, packets),
+        };
+
+        // Parsing made sure there are enough sub-packets, so the fallbacks
+        // never come into play.
+        let mut values = packets.iter().map(|p| p.evaluate());
+        match operator {
+            Operator::Sum => values.sum(),
+            Operator::Product => values.product(),
+            Operator::Minimum => values.min().unwrap_or_default(),
+            Operator::Maximum => values.max().unwrap_or_default(),
+            Operator::GreaterThan => (values.next() > values.next()) as usize,
+            Operator::LessThan => (values.next() < values.next()) as usize,
+            Operator::EqualTo => (values.next() == values.next()) as usize,
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::end(input, "a hexadecimal transmission"))?;
+        let text = line.text.trim();
+        let data = hex::decode(text).map_err(|e| match e {
+            hex::FromHexError::InvalidHexCharacter { index, .. } => {
+                line.error(text.get(index..).unwrap_or(text), "a hexadecimal digit")
+            }
+            _ => line.error(text, "an even number of hexadecimal digits"),
+        })?;
+
+        match Packet::parse_bytes(&data) {
+            Ok((packet, _)) => Ok(packet),
+            Err(Malformed::Truncated) => Err(line.error(text, "a complete packet")),
+            Err(Malformed::Operands(operator)) => Err(line.error(text, operator.expected())),
+        }
+    }
+
+    fn part1(packet: &Self::Input) -> Answer {
+        packet.version_sum().into()
+    }
+
+    fn part2(packet: &Self::Input) -> Answer {
+        packet.evaluate().into()
+    }
+}
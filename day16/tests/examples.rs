@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day16::Day16>(env!("CARGO_MANIFEST_DIR"));
+}
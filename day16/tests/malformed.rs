@@ -0,0 +1,17 @@
+use aoc_common::Solution;
+use day16::Day16;
+
+#[test]
+fn missing_sub_packets() {
+    let e = Day16::parse("0A0000\n").unwrap_err();
+    assert_eq!(e.expected, "a minimum packet with at least one sub-packet");
+
+    let e = Day16::parse("1A004428\n").unwrap_err();
+    assert_eq!(e.expected, "a less-than packet with two sub-packets");
+}
+
+#[test]
+fn truncated() {
+    let e = Day16::parse("1A\n").unwrap_err();
+    assert_eq!(e.expected, "a complete packet");
+}
@@ -1,6 +1,9 @@
 use std::collections::HashSet;
 use std::ops::RangeInclusive;
 
+use aoc_common::parse::{self, InputLine};
+use aoc_common::{Answer, ParseError, Solution};
+
 #[derive(Debug)]
 enum Time {
     Transient(usize),
@@ -86,43 +89,29 @@ impl Iterator for YGenerator {
     }
 }
 
-fn main() {
-    let input = std::fs::read_to_string(std::env::args_os().nth(1).unwrap()).unwrap();
-    let input: Vec<&str> = input.trim().split_whitespace().collect();
-
-    let x_range: Vec<isize> = input[2][2..]
-        .trim_end_matches(',')
-        .split_terminator("..")
-        .map(|v| v.parse::<isize>().unwrap())
-        .collect();
-    let min_x = isize::min(x_range[0], x_range[1]);
-    let max_x = isize::max(x_range[0], x_range[1]);
-    let x_range = min_x..=max_x;
+pub struct Target {
+    x_range: RangeInclusive<isize>,
+    y_range: RangeInclusive<isize>,
+}
 
-    let y_range: Vec<isize> = input[3][2..]
-        .split_terminator("..")
-        .map(|v| v.parse::<isize>().unwrap())
-        .collect();
-    let min_y = isize::min(y_range[0], y_range[1]);
-    let max_y = isize::max(y_range[0], y_range[1]);
-    let y_range = min_y..=max_y;
+fn trajectories(target: &Target) -> HashSet<(isize, isize)> {
+    let x_range = &target.x_range;
+    let y_range = &target.y_range;
 
-    let dx_candidates: Vec<(Time, isize)> = (0..=max_x)
-        .map(|dx| {
+    let dx_candidates: Vec<(Time, isize)> = (0..=*x_range.end())
+        .flat_map(|dx| {
             XGenerator::new(dx, x_range.clone())
                 .filter(|(_, x)| x_range.contains(x))
                 .map(move |(t, _)| (t, dx))
         })
-        .flatten()
         .collect();
 
-    let dy_candidates: Vec<(usize, isize)> = (min_y..1000)
-        .map(|dy| {
+    let dy_candidates: Vec<(usize, isize)> = (*y_range.start()..1000)
+        .flat_map(|dy| {
             YGenerator::new(dy, y_range.clone())
                 .filter(|(_, y)| y_range.contains(y))
                 .map(move |(t, _)| (t, dy))
         })
-        .flatten()
         .collect();
 
     let mut d_candidates = HashSet::new();
@@ -139,5 +128,54 @@ fn main() {
         }
     }
 
-    println!("Trajectories: {}", d_candidates.len());
+    d_candidates
+}
+
+fn range(line: &InputLine, text: &str) -> Result<RangeInclusive<isize>, ParseError> {
+    let (a, b) = text
+        .split_once("..")
+        .ok_or_else(|| line.error(text, "a range like 20..30"))?;
+    let a = line.field::<isize>(a, "a number")?;
+    let b = line.field::<isize>(b, "a number")?;
+
+    Ok(isize::min(a, b)..=isize::max(a, b))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Target;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = parse::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::end(input, "a target area"))?;
+        let [x, y] = line.split(", y=", "a target like target area: x=20..30, y=-10..-5")?;
+        let x = x
+            .strip_prefix("target area: x=")
+            .ok_or_else(|| line.error(x, "target area: x="))?;
+
+        Ok(Target {
+            x_range: range(&line, x)?,
+            y_range: range(&line, y.trim_end())?,
+        })
+    }
+
+    fn part1(target: &Self::Input) -> Answer {
+        trajectories(target)
+            .iter()
+            .map(|&(_, dy)| {
+                YGenerator::new(dy, target.y_range.clone())
+                    .map(|(_, y)| y)
+                    .max()
+                    .unwrap()
+            })
+            .max()
+            .unwrap()
+            .into()
+    }
+
+    fn part2(target: &Self::Input) -> Answer {
+        trajectories(target).len().into()
+    }
 }
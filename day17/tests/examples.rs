@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day17::Day17>(env!("CARGO_MANIFEST_DIR"));
+}
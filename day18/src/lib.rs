@@ -1,7 +1,6 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::ops::Add;
 
+use aoc_common::{parse, Answer, ParseError, Solution};
 use itertools::Itertools;
 use num_integer::Integer;
 use serde_json::Value;
@@ -15,24 +14,24 @@ enum SnailDigit {
 }
 
 #[derive(Clone, Debug)]
-struct SnailNumber(Vec<SnailDigit>);
+pub struct SnailNumber(Vec<SnailDigit>);
 
-impl From<Value> for SnailNumber {
-    fn from(value: Value) -> Self {
-        SnailNumber::from(&value)
-    }
-}
+impl TryFrom<&Value> for SnailNumber {
+    type Error = &'static str;
 
-impl From<&Value> for SnailNumber {
-    fn from(value: &Value) -> Self {
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
         let mut number = Vec::new();
 
         match value {
-            Value::Number(n) => number.push(SnailDigit::Number(n.as_i64().unwrap())),
+            Value::Number(n) => {
+                number.push(SnailDigit::Number(n.as_i64().ok_or("a regular number")?))
+            }
             Value::Array(array) => {
-                assert!(array.len() == 2, "pair isn't a pair");
-                let mut a = SnailNumber::from(&array[0]);
-                let mut b = SnailNumber::from(&array[1]);
+                if array.len() != 2 {
+                    return Err("a pair of two elements");
+                }
+                let mut a = SnailNumber::try_from(&array[0])?;
+                let mut b = SnailNumber::try_from(&array[1])?;
 
                 number.push(SnailDigit::PairOpen);
                 number.append(&mut a.0);
@@ -40,10 +39,10 @@ impl From<&Value> for SnailNumber {
                 number.append(&mut b.0);
                 number.push(SnailDigit::PairClose);
             }
-            _ => panic!("unexpected value"),
+            _ => return Err("a pair or a regular number"),
         }
 
-        SnailNumber(number)
+        Ok(SnailNumber(number))
     }
 }
 
@@ -170,8 +169,8 @@ impl SnailNumber {
                 panic!("eek");
             };
 
-            let a = number.div_floor(&2);
-            let b = number.div_ceil(&2);
+            let a = Integer::div_floor(&number, &2);
+            let b = Integer::div_ceil(&number, &2);
 
             self.0.remove(pos);
             self.0.insert(pos, SnailDigit::PairOpen);
@@ -207,36 +206,41 @@ impl SnailNumber {
     }
 }
 
-fn main() {
-    let file = File::open(std::env::args_os().nth(1).unwrap()).unwrap();
-    let reader = BufReader::new(file);
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<SnailNumber>;
 
-    let numbers: Vec<SnailNumber> = reader
-        .lines()
-        .map(|l| {
-            let l = l.unwrap();
-            serde_json::from_str::<Value>(&l).unwrap().into()
-        })
-        .collect();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let numbers: Vec<SnailNumber> = parse::lines(input)
+            .map(|line| {
+                let value = serde_json::from_str::<Value>(line.text).map_err(|e| {
+                    ParseError::new(line.number, e.column().max(1), "a snailfish number")
+                })?;
+                SnailNumber::try_from(&value).map_err(|expected| line.error(line.text, expected))
+            })
+            .collect::<Result<_, _>>()?;
 
-    for number in &numbers {
-        println!("{}", number);
+        if numbers.is_empty() {
+            return Err(ParseError::end(input, "a snailfish number"));
+        }
+
+        Ok(numbers)
     }
 
-    println!();
-
-    let (a, b, sum, magnitude) = numbers
-        .iter()
-        .permutations(2)
-        .map(move |ns| {
-            let a = ns[0].clone();
-            let b = ns[1].clone();
-            let sum = ns[0].clone() + ns[1].clone();
-            let magnitude = sum.magnitude();
-            (a, b, sum, magnitude)
-        })
-        .max_by_key(|(_, _, _, m)| *m)
-        .unwrap();
-    println!("{} + {} = {}", a, b, sum);
-    println!("Magnitude: {}", magnitude);
+    fn part1(numbers: &Self::Input) -> Answer {
+        let total = numbers.iter().cloned().reduce(|a, e| a + e).unwrap();
+        total.magnitude().into()
+    }
+
+    fn part2(numbers: &Self::Input) -> Answer {
+        numbers
+            .iter()
+            .permutations(2)
+            .map(|ns| (ns[0].clone() + ns[1].clone()).magnitude())
+            .max()
+            // A lone number has nothing to be added to.
+            .unwrap_or(0)
+            .into()
+    }
 }
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day18::Day18>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,15 @@
+use aoc_common::Solution;
+use day18::Day18;
+
+#[test]
+fn needs_a_number() {
+    let e = Day18::parse("").unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (1, "a snailfish number"));
+}
+
+#[test]
+fn lone_number() {
+    let numbers = Day18::parse("[[1,2],[[3,4],5]]\n").unwrap();
+    assert_eq!(Day18::part1(&numbers), 143.into());
+    assert_eq!(Day18::part2(&numbers), 0.into());
+}
@@ -1,8 +1,7 @@
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::ops::{Add, AddAssign, Neg, Sub};
 
+use aoc_common::{parse, Answer, ParseError, Solution};
 use itertools::Itertools;
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -119,6 +118,7 @@ impl Add<AxisRotation> for AxisRotation {
 impl Sub<AxisRotation> for AxisRotation {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn sub(self, rhs: Self) -> Self {
         let rhs = match rhs {
             AxisRotation::None => 0,
@@ -142,6 +142,7 @@ impl Add<usize> for AxisRotation {
 }
 
 impl AddAssign<usize> for AxisRotation {
+    #[allow(clippy::suspicious_op_assign_impl)]
     fn add_assign(&mut self, rhs: usize) {
         for _ in 0..rhs % 4 {
             *self = match self {
@@ -256,7 +257,7 @@ impl Iterator for Rotator {
 }
 
 #[derive(Debug, Default)]
-struct Region {
+pub struct Region {
     scanners: HashMap<usize, (Pointlike, Vec<Pointlike>)>,
     beacons: HashSet<Pointlike>,
 }
@@ -310,15 +311,11 @@ impl Region {
         if !self.scanners.is_empty() {
             let mut found = false;
 
-            for (eid, (_, existing)) in &self.scanners {
+            for (_, existing) in self.scanners.values() {
                 if let Some((r, t)) = Self::compare(existing, beacon_locations) {
                     found = true;
                     rotation = r;
                     translation = t;
-                    println!(
-                        "Scanner {:2} matched scanner {:2} @ translation {}, rotation {}",
-                        id, eid, t, r
-                    );
                     break;
                 }
             }
@@ -346,40 +343,10 @@ impl Region {
         true
     }
 
-    fn describe(&self) {
-        let mut scanners: Vec<(usize, Pointlike)> =
-            self.scanners.iter().map(|(id, (l, _))| (*id, *l)).collect();
-        scanners.sort_unstable_by_key(|(id, _)| *id);
-
-        for (id, location) in scanners {
-            println!(
-                "Scanner {} @ ({:5}, {:5}, {:5})",
-                id, location.0, location.1, location.2
-            );
-        }
-
-        println!();
-
-        let mut beacons: Vec<&Pointlike> = self.beacons.iter().collect();
-        beacons.sort_unstable();
-
-        for beacon in beacons {
-            println!("Beacon @ ({:5}, {:5}, {:5})", beacon.0, beacon.1, beacon.2);
-        }
-
-        println!();
-
-        println!(
-            "Scanners: {}, Beacons: {}",
-            self.scanners.len(),
-            self.beacons.len()
-        );
-    }
-
     fn largest_distance(&self) -> (usize, usize, usize) {
         self.scanners
             .iter()
-            .combinations(2)
+            .combinations_with_replacement(2)
             .map(|s| (*s[0].0, *s[1].0, s[0].1 .0.distance(&s[1].1 .0)))
             .max_by_key(|(_, _, d)| *d)
             .unwrap()
@@ -411,43 +378,58 @@ impl FromIterator<(usize, Vec<Pointlike>)> for Region {
     }
 }
 
-fn main() {
-    let file = File::open(std::env::args_os().nth(1).unwrap()).unwrap();
-    let reader = BufReader::new(file);
+pub struct Day19;
 
-    let mut scanners = Vec::new();
-    let mut beacons = Vec::new();
-    let mut scanner_id = 0;
+impl Solution for Day19 {
+    type Input = Region;
 
-    for line in reader.lines() {
-        let line = line.unwrap();
-        if line.starts_with("---") {
-            continue;
-        } else if line.is_empty() {
-            scanners.push((scanner_id, beacons));
-            scanner_id += 1;
-            beacons = Vec::new();
-            continue;
-        }
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut scanners = Vec::new();
+        let mut beacons = Vec::new();
+        let mut scanner_id = 0;
 
-        let point: Vec<i64> = line
-            .split_terminator(',')
-            .map(|v| v.parse::<i64>().unwrap())
-            .collect();
+        for line in parse::lines(input) {
+            if line.text.starts_with("---") {
+                continue;
+            } else if line.text.is_empty() {
+                scanners.push((scanner_id, beacons));
+                scanner_id += 1;
+                beacons = Vec::new();
+                continue;
+            }
+
+            let point = line
+                .text
+                .split_terminator(',')
+                .map(|v| line.field::<i64>(v, "a coordinate"))
+                .collect::<Result<Vec<i64>, ParseError>>()?;
+            if !(2..=3).contains(&point.len()) {
+                return Err(line.error(line.text, "a beacon like -618,-824,-621"));
+            }
+
+            let x = point[0];
+            let y = point[1];
+            let z = if point.len() == 3 { point[2] } else { 0 };
 
-        let x = point[0];
-        let y = point[1];
-        let z = if point.len() == 3 { point[2] } else { 0 };
+            beacons.push(Pointlike(x, y, z));
+        }
+
+        if !beacons.is_empty() {
+            scanners.push((scanner_id, beacons));
+        }
+        if scanners.is_empty() {
+            return Err(ParseError::end(input, "a scanner's beacons"));
+        }
 
-        beacons.push(Pointlike(x, y, z));
+        Ok(scanners.into_iter().collect())
     }
 
-    if !beacons.is_empty() {
-        scanners.push((scanner_id, beacons));
+    fn part1(region: &Self::Input) -> Answer {
+        region.beacons.len().into()
     }
 
-    let region: Region = scanners.into_iter().collect();
-    region.describe();
-    let (a, b, distance) = region.largest_distance();
-    println!("Largest distance: {} - {}: {}", a, b, distance);
+    fn part2(region: &Self::Input) -> Answer {
+        let (_, _, distance) = region.largest_distance();
+        distance.into()
+    }
 }
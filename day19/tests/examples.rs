@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day19::Day19>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,15 @@
+use aoc_common::Solution;
+use day19::Day19;
+
+#[test]
+fn needs_a_scanner() {
+    let e = Day19::parse("").unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (1, "a scanner's beacons"));
+}
+
+#[test]
+fn lone_scanner() {
+    let region = Day19::parse("--- scanner 0 ---\n0,2\n4,1\n3,3\n").unwrap();
+    assert_eq!(Day19::part1(&region), 3.into());
+    assert_eq!(Day19::part2(&region), 0.into());
+}
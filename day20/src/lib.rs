@@ -0,0 +1,108 @@
+use aoc_common::automaton::{Automaton, Boundary, Rule, View};
+use aoc_common::grid::{self, Grid};
+use aoc_common::heatmap::Heatmap;
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(usize)]
+enum Pixel {
+    Light = 1,
+    Dark = 0,
+}
+
+impl TryFrom<char> for Pixel {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Self::Light),
+            '.' => Ok(Self::Dark),
+            _ => Err(c),
+        }
+    }
+}
+
+impl std::fmt::Display for Pixel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Light => write!(f, "#"),
+            Self::Dark => write!(f, "."),
+        }
+    }
+}
+
+/// The image enhancement algorithm: each pixel becomes the entry indexed
+/// by the 3x3 square of pixels around it, read as a binary number.
+struct Enhancer(Vec<Pixel>);
+
+impl Rule<Pixel> for Enhancer {
+    fn next(&self, _phase: usize, view: &View<Pixel>) -> Pixel {
+        let mut value = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                value = value << 1 | *view.at(dx, dy).unwrap() as usize;
+            }
+        }
+        self.0[value]
+    }
+}
+
+pub struct Image {
+    enhancer: Enhancer,
+    image: Grid<Pixel>,
+}
+
+fn enhance(image: &Image, times: usize) -> Grid<Pixel> {
+    let mut automaton = Automaton::new(image.image.clone(), Boundary::Infinite(Pixel::Dark));
+    automaton.run(&image.enhancer, times);
+    automaton.grid().clone()
+}
+
+fn lit_pixels(image: &Grid<Pixel>) -> usize {
+    image.iter().filter(|&&p| p == Pixel::Light).count()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Image;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input);
+
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::end(input, "an enhancement algorithm"))?;
+        let enhancer = grid::cells([line], "# or .", |c| Pixel::try_from(c).ok())?.remove(0);
+        if enhancer.len() != 512 {
+            return Err(line.error(line.text, "512 pixels of enhancement algorithm"));
+        }
+
+        let image = Grid::parse(lines.filter(|line| !line.text.is_empty()), "# or .", |c| {
+            Pixel::try_from(c).ok()
+        })?;
+
+        Ok(Image {
+            enhancer: Enhancer(enhancer),
+            image,
+        })
+    }
+
+    fn part1(image: &Self::Input) -> Answer {
+        lit_pixels(&enhance(image, 2)).into()
+    }
+
+    fn part2(image: &Self::Input) -> Answer {
+        lit_pixels(&enhance(image, 50)).into()
+    }
+
+    fn heatmap(input: &str, part: u8) -> Result<Option<Heatmap>, ParseError> {
+        let times = match part {
+            1 => 2,
+            2 => 50,
+            _ => return Ok(None),
+        };
+        let image = enhance(&Self::parse(input)?, times);
+        Ok(Some(Heatmap::from_grid(&image, |&p| p as usize as f64)))
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day20::Day20>(env!("CARGO_MANIFEST_DIR"));
+}
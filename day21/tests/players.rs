@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day21::Day21;
+
+#[test]
+fn exactly_two_players() {
+    let e = Day21::parse("").unwrap_err();
+    assert_eq!(e.expected, "a starting position for player 1");
+
+    let e = Day21::parse("Player 1 starting position: 4\n").unwrap_err();
+    assert_eq!(
+        (e.line, e.expected.as_str()),
+        (2, "a starting position for player 2")
+    );
+
+    let e = Day21::parse(
+        "Player 1 starting position: 4\n\
+         Player 2 starting position: 8\n\
+         Player 3 starting position: 1\n",
+    )
+    .unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (3, "only two players"));
+}
@@ -1,11 +1,12 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::collections::HashSet;
 use std::ops::RangeInclusive;
 
+use aoc_common::parse::{self, InputLine};
+use aoc_common::{Answer, ParseError, Solution};
 use itertools::Itertools;
 
 #[derive(Clone, Debug)]
-struct BunchOfCubes(
+pub struct BunchOfCubes(
     RangeInclusive<isize>,
     RangeInclusive<isize>,
     RangeInclusive<isize>,
@@ -95,8 +96,6 @@ impl BunchOfCubes {
     }
 
     fn carve(&self, other: &Self) -> Vec<BunchOfCubes> {
-        let removing = other.count();
-
         let mut victim = self.clone();
         let mut new_cubes = Vec::new();
 
@@ -106,7 +105,6 @@ impl BunchOfCubes {
                 victim.1.clone(),
                 victim.2.clone(),
             );
-            println!("    x-start: {}", new);
             new_cubes.push(new);
             victim.0 = *other.0.start()..=*victim.0.end();
         }
@@ -117,7 +115,6 @@ impl BunchOfCubes {
                 victim.1.clone(),
                 victim.2.clone(),
             );
-            println!("    x-end: {}", new);
             new_cubes.push(new);
             victim.0 = *victim.0.start()..=*other.0.end();
         }
@@ -128,7 +125,6 @@ impl BunchOfCubes {
                 *victim.1.start()..=(*other.1.start() - 1),
                 victim.2.clone(),
             );
-            println!("    y-start: {}", new);
             new_cubes.push(new);
             victim.1 = *other.1.start()..=*victim.1.end();
         }
@@ -139,7 +135,6 @@ impl BunchOfCubes {
                 *other.1.end() + 1..=(*victim.1.end()),
                 victim.2.clone(),
             );
-            println!("    y-end: {}", new);
             new_cubes.push(new);
             victim.1 = *victim.1.start()..=*other.1.end();
         }
@@ -150,7 +145,6 @@ impl BunchOfCubes {
                 victim.1.clone(),
                 *victim.2.start()..=(*other.2.start() - 1),
             );
-            println!("    z-start: {}", new);
             new_cubes.push(new);
 
             victim.2 = *other.2.start()..=*victim.2.end();
@@ -162,19 +156,9 @@ impl BunchOfCubes {
                 victim.1.clone(),
                 *other.2.end() + 1..=(*victim.2.end()),
             );
-            println!("    z-end: {}", new);
             new_cubes.push(new);
-            victim.2 = *victim.2.start()..=*other.2.end();
         }
 
-        let remaining = new_cubes.iter().map(|c| c.count()).sum::<isize>();
-        println!(
-            "    remaining {} removed {} total {}",
-            remaining,
-            removing,
-            remaining + removing
-        );
-
         new_cubes
     }
 
@@ -185,76 +169,119 @@ impl BunchOfCubes {
     }
 }
 
-fn main() {
-    let file = File::open(std::env::args_os().nth(1).unwrap()).unwrap();
-    let reader = BufReader::new(file);
-
-    let mut cuboids: Vec<BunchOfCubes> = Vec::new();
-
-    for (line_number, line) in reader.lines().enumerate() {
-        let line = line.unwrap();
-        println!("{}: {}", line_number + 1, line);
-        let (state, ranges) = line.split_whitespace().collect_tuple().unwrap();
-        let (x_range, y_range, z_range) = if let Some(r) = ranges
-            .split_terminator(',')
-            .map(|r| {
-                let (start, end) = r
-                    .split_at(2)
-                    .1
-                    .split_terminator("..")
+fn range(line: &InputLine, text: &str, axis: &str) -> Result<RangeInclusive<isize>, ParseError> {
+    let (start, end) = text
+        .strip_prefix(axis)
+        .and_then(|r| r.split_once(".."))
+        .ok_or_else(|| line.error(text, format!("a range like {}10..12", axis)))?;
+
+    Ok(line.field(start, "a number")?..=line.field(end, "a number")?)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<(bool, BunchOfCubes)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                let [state, ranges] =
+                    line.split(" ", "a step like on x=10..12,y=10..12,z=10..12")?;
+                let state = match state {
+                    "on" => true,
+                    "off" => false,
+                    _ => return Err(line.error(state, "on or off")),
+                };
+                let (x_range, y_range, z_range) = ranges
+                    .split_terminator(',')
+                    .zip(["x=", "y=", "z="])
+                    .map(|(r, axis)| range(&line, r, axis))
+                    .collect::<Result<Vec<_>, ParseError>>()?
+                    .into_iter()
                     .collect_tuple()
-                    .unwrap();
+                    .ok_or_else(|| line.error(ranges, "ranges for x, y and z"))?;
 
-                start.parse::<isize>().unwrap()..=end.parse::<isize>().unwrap()
+                Ok((state, BunchOfCubes::new(x_range, y_range, z_range)))
             })
-            .collect_tuple()
-        {
-            r
-        } else {
-            continue;
-        };
+            .collect()
+    }
+
+    fn part1(steps: &Self::Input) -> Answer {
+        let mut cubes = HashSet::new();
 
-        let on = state == "on";
-        let cuboid = BunchOfCubes::new(x_range, y_range, z_range);
-        let mut new_cuboids = Vec::new();
-        let mut add = on;
-
-        cuboids.reverse();
-
-        for existing_cubes in cuboids {
-            if on && existing_cubes.encloses(&cuboid) {
-                println!("existing {} encloses {}", existing_cubes, cuboid);
-                new_cuboids.push(existing_cubes);
-                add = false;
-                break;
-            } else if on && cuboid.encloses(&existing_cubes) {
-                println!("{} encloses existing {}", cuboid, existing_cubes);
-            } else if !on && cuboid.encloses(&existing_cubes) {
-                println!("{} encloses existing {} and is off", cuboid, existing_cubes);
-            } else if let Some(overlap) = cuboid.overlap_cubes(&existing_cubes) {
-                println!("carving {} out of existing {}", overlap, existing_cubes);
-                new_cuboids.append(&mut existing_cubes.carve(&overlap));
+        for (on, cuboid) in steps {
+            let (x_range, y_range, z_range) = if let Some(r) = [&cuboid.0, &cuboid.1, &cuboid.2]
+                .into_iter()
+                .filter_map(|r| {
+                    let (start, end) = (*r.start(), *r.end());
+
+                    if (start > 50 && end > 50) || (start < -50 && end < -50) {
+                        return None;
+                    }
+
+                    Some(start.clamp(-50, 50)..=end.clamp(-50, 50))
+                })
+                .collect_tuple()
+            {
+                r
             } else {
-                println!("{} carried over", existing_cubes);
-                new_cuboids.push(existing_cubes);
+                continue;
+            };
+
+            for x in x_range.clone() {
+                for y in y_range.clone() {
+                    for z in z_range.clone() {
+                        if *on {
+                            cubes.insert((x, y, z));
+                        } else {
+                            cubes.remove(&(x, y, z));
+                        }
+                    }
+                }
             }
         }
 
-        new_cuboids.reverse();
+        cubes.len().into()
+    }
+
+    fn part2(steps: &Self::Input) -> Answer {
+        let mut cuboids: Vec<BunchOfCubes> = Vec::new();
+
+        for (on, cuboid) in steps {
+            let on = *on;
+            let mut new_cuboids = Vec::new();
+            let mut add = on;
+
+            cuboids.reverse();
+
+            for existing_cubes in cuboids {
+                if on && existing_cubes.encloses(cuboid) {
+                    new_cuboids.push(existing_cubes);
+                    add = false;
+                    break;
+                } else if cuboid.encloses(&existing_cubes) {
+                    // Either already covered by the new cuboid or switched off by it.
+                } else if let Some(overlap) = cuboid.overlap_cubes(&existing_cubes) {
+                    new_cuboids.append(&mut existing_cubes.carve(&overlap));
+                } else {
+                    new_cuboids.push(existing_cubes);
+                }
+            }
 
-        if add {
-            println!("adding {}", cuboid);
-            new_cuboids.push(cuboid.clone());
+            new_cuboids.reverse();
+
+            if add {
+                new_cuboids.push(cuboid.clone());
+            }
+
+            cuboids = new_cuboids;
         }
 
-        cuboids = new_cuboids;
-        println!(
-            "After line {}: {} cubes",
-            line_number + 1,
-            cuboids.iter().map(|cubes| cubes.count()).sum::<isize>(),
-        );
+        cuboids
+            .iter()
+            .map(|cubes| cubes.count())
+            .sum::<isize>()
+            .into()
     }
-
-    let count = cuboids.iter().map(|cubes| cubes.count()).sum::<isize>();
-    println!("{} cubes in {} cuboids", count, cuboids.len());
 }
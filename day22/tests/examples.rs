@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day22::Day22>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -1,14 +1,17 @@
-use std::cmp::Reverse;
+use std::cell::RefCell;
 use std::collections::{hash_map::Entry, BTreeMap, HashMap, HashSet};
 use std::fmt::Write as FmtWrite;
 use std::hash::{Hash, Hasher};
 use std::io::Write;
+use std::time::Instant;
 
+use aoc_common::render::{Headless, Renderer};
+use aoc_common::search::{self, Progress};
+use aoc_common::{parse, Answer, ParseError, Solution};
 use crossterm::{cursor, terminal, QueueableCommand};
-use priority_queue::PriorityQueue;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-enum Room {
+pub enum Room {
     A,
     B,
     C,
@@ -26,13 +29,10 @@ impl Room {
     }
 
     const fn is_home_for_amphipod(&self, amphipod: char) -> bool {
-        match (self, amphipod) {
-            (Self::A, 'A') => true,
-            (Self::B, 'B') => true,
-            (Self::C, 'C') => true,
-            (Self::D, 'D') => true,
-            _ => false,
-        }
+        matches!(
+            (self, amphipod),
+            (Self::A, 'A') | (Self::B, 'B') | (Self::C, 'C') | (Self::D, 'D')
+        )
     }
 
     fn home_for_amphipod(amphipod: char) -> Self {
@@ -51,7 +51,7 @@ impl Room {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-enum Position {
+pub enum Position {
     Hallway(u8),
     Room(Room, u8),
 }
@@ -105,21 +105,19 @@ impl Position {
         match (self, other) {
             (Self::Room(r1, p1), Self::Room(r2, p2)) => {
                 (*p1 as usize + 1)
-                    + (r1.hallway_outside() as i8 - r2.hallway_outside() as i8).abs() as usize
+                    + (r1.hallway_outside() as i8 - r2.hallway_outside() as i8).unsigned_abs()
+                        as usize
                     + (*p2 as usize + 1)
             }
             (Self::Room(r, p), Self::Hallway(h)) | (Self::Hallway(h), Self::Room(r, p)) => {
-                (*p as usize + 1) + (r.hallway_outside() as i8 - *h as i8).abs() as usize
+                (*p as usize + 1) + (r.hallway_outside() as i8 - *h as i8).unsigned_abs() as usize
             }
             _ => panic!("eeeek"),
         }
     }
 
     const fn is_home_for_amphipod(&self, amphipod: char) -> bool {
-        match (self, amphipod) {
-            (Self::Room(r, _), a) if r.is_home_for_amphipod(a) => true,
-            _ => false,
-        }
+        matches!((self, amphipod), (Self::Room(r, _), a) if r.is_home_for_amphipod(a))
     }
 
     fn blocks_in_room(&self, other: &Self) -> bool {
@@ -141,6 +139,7 @@ type BurrowId = u64;
 
 #[derive(Default)]
 struct Burrowverse {
+    depth: u8,
     burrows: HashMap<BurrowId, BTreeMap<Position, char>>,
     moves: HashMap<BurrowId, Vec<(BurrowId, usize)>>,
     approx_cost: HashMap<BurrowId, usize>,
@@ -149,24 +148,28 @@ struct Burrowverse {
 }
 
 impl Burrowverse {
-    fn parse_burrow(input: &str) -> BTreeMap<Position, char> {
+    fn parse_burrow(input: &str) -> Result<BTreeMap<Position, char>, ParseError> {
         let mut positions = BTreeMap::new();
 
-        for (p, line) in input
-            .split_terminator('\n')
-            .filter(|&l| l.chars().any(|c| char::is_ascii_uppercase(&c)))
+        for (p, line) in parse::lines(input)
+            .filter(|l| l.text.chars().any(|c| char::is_ascii_uppercase(&c)))
             .enumerate()
         {
             for room in Room::all() {
-                let marker = line
-                    .chars()
-                    .nth(room.hallway_outside() as usize + 1)
-                    .unwrap();
+                let column = room.hallway_outside() as usize + 1;
+                let marker = match line.text.chars().nth(column) {
+                    Some(marker @ 'A'..='D') => marker,
+                    _ => return Err(ParseError::new(line.number, column + 1, "an amphipod")),
+                };
                 positions.insert(Position::Room(room, p as u8), marker);
             }
         }
 
-        positions
+        if positions.len() != 8 {
+            return Err(ParseError::end(input, "two rows of amphipods"));
+        }
+
+        Ok(positions)
     }
 
     fn print(&self, burrow: BurrowId) {
@@ -189,7 +192,7 @@ impl Burrowverse {
         }
         writeln!(result, "#").unwrap();
 
-        for room_position in 0..=3 {
+        for room_position in 0..self.depth {
             if room_position == 0 {
                 write!(result, "          ###").unwrap();
             } else {
@@ -349,7 +352,7 @@ impl Burrowverse {
                     && matches!(p, Position::Room(r, _) if r == Room::home_for_amphipod(amphipod))
             }) {
                 // Our room is available, let's head there.
-                if let Some(target) = (0..=3)
+                if let Some(target) = (0..self.depth)
                     .rev()
                     .map(|rp| Position::Room(Room::home_for_amphipod(amphipod), rp))
                     .find(|p| !occupied_positions.contains(p))
@@ -406,134 +409,138 @@ impl Burrowverse {
     }
 }
 
-#[derive(Default)]
-struct Path(HashMap<BurrowId, (BurrowId, usize)>);
+/// Redraws the burrow being looked at, along with some statistics, after
+/// every cycle and then prints the cheapest way to the goal.
+struct Terminal<'a> {
+    burrowverse: &'a RefCell<Burrowverse>,
+    stdout: std::io::Stdout,
+    last_frame: Instant,
+}
 
-impl Path {
-    fn insert(&mut self, to: BurrowId, from: BurrowId, cost: usize) {
-        self.0.insert(to, (from, cost));
+impl<'a> Terminal<'a> {
+    fn new(burrowverse: &'a RefCell<Burrowverse>) -> Self {
+        Self {
+            burrowverse,
+            stdout: std::io::stdout(),
+            last_frame: Instant::now(),
+        }
     }
+}
 
-    fn cost_from(&self, from: BurrowId) -> usize {
-        let mut from = from;
-        let mut energy_used = 0;
-
-        while let Some(prev) = self.0.get(&from) {
-            energy_used += prev.1;
-            from = prev.0;
-        }
+impl Renderer<Progress<'_, BurrowId>> for Terminal<'_> {
+    fn begin(&mut self, progress: &Progress<BurrowId>) {
+        self.burrowverse.borrow().print(*progress.current);
 
-        energy_used
+        self.stdout
+            .queue(terminal::Clear(terminal::ClearType::All))
+            .unwrap();
+        self.stdout.queue(cursor::Hide).unwrap();
+        self.stdout.flush().unwrap();
+        self.last_frame = Instant::now();
     }
 
-    fn path_len_to(&self, to: BurrowId) -> usize {
-        let mut from = to;
-        let mut length = 0;
+    fn frame(&mut self, progress: &Progress<BurrowId>) {
+        let cycle_time = self.last_frame.elapsed();
 
-        while let Some(prev) = self.0.get(&from) {
-            from = prev.0;
-            length += 1;
-        }
+        self.stdout.queue(cursor::MoveTo(0, 0)).unwrap();
+        write!(
+            self.stdout,
+            "{}",
+            self.burrowverse.borrow().format(*progress.current)
+        )
+        .unwrap();
+        self.stdout.queue(cursor::MoveTo(0, 12)).unwrap();
+        write!(
+            self.stdout,
+            "cycles:      {:20}\nopen states: {:20}\nenergy:      {:20}\ncycle time:  {:20}µs",
+            progress.stats.expanded,
+            progress.stats.open,
+            progress.cost,
+            cycle_time.as_micros()
+        )
+        .unwrap();
 
-        length
+        self.last_frame = Instant::now();
     }
 
-    fn path_to(&self, to: BurrowId) -> Vec<BurrowId> {
-        let mut from = to;
-        let mut path = vec![from];
+    fn finish(&mut self, progress: &Progress<BurrowId>) {
+        self.stdout.queue(cursor::Show).unwrap();
+        self.stdout.flush().unwrap();
 
-        while let Some(prev) = self.0.get(&from) {
-            from = prev.0;
-            path.push(from);
+        let burrowverse = self.burrowverse.borrow();
+        if !burrowverse.is_goal(*progress.current) {
+            println!("\nWE FAIL AFTER {}", progress.stats.expanded);
         }
 
-        path.reverse();
-        path
+        println!();
+        for burrow in progress.path() {
+            burrowverse.print(burrow);
+        }
     }
 }
 
-fn main() {
-    let input = std::fs::read_to_string(std::env::args_os().nth(1).unwrap()).unwrap();
-    let start = Burrowverse::parse_burrow(&input);
-
-    let mut burrowverse = Box::new(Burrowverse::default());
-    let mut current = burrowverse.get_or_insert(start);
-    burrowverse.print(current);
-
-    let mut open: PriorityQueue<BurrowId, Reverse<usize>> = PriorityQueue::new();
-    open.push(current, Reverse(burrowverse.approx_cost(current)));
-    let mut g = HashMap::from([(current, 0)]);
-    let mut came_from = Path::default();
-
-    let mut stdout = std::io::stdout();
-    stdout
-        .queue(terminal::Clear(terminal::ClearType::All))
-        .unwrap();
-    stdout.queue(cursor::Hide).unwrap();
-    stdout.flush().unwrap();
-
-    let mut cycle_counter: usize = 0;
-
-    while let Some((next, _)) = open.pop() {
-        let start = std::time::Instant::now();
-
-        current = next;
+fn minimum_energy(
+    burrowverse: &RefCell<Burrowverse>,
+    start: BTreeMap<Position, char>,
+    renderer: &mut impl for<'a> Renderer<Progress<'a, BurrowId>>,
+) -> usize {
+    burrowverse.borrow_mut().depth = start.len() as u8 / 4;
+    let start = burrowverse.borrow_mut().get_or_insert(start);
+
+    search::astar(
+        start,
+        |&burrow| burrowverse.borrow_mut().moves(burrow),
+        |&burrow| burrowverse.borrow().approx_cost(burrow),
+        |&burrow| burrowverse.borrow().is_goal(burrow),
+        renderer,
+    )
+    .unwrap()
+    .cost
+}
 
-        if burrowverse.is_goal(current) {
-            break;
+/// Unfolds the diagram for part 2, pushing the back row of each room down to
+/// make space for the two extra rows.
+fn unfold(burrow: &BTreeMap<Position, char>) -> BTreeMap<Position, char> {
+    let mut unfolded = burrow.clone();
+    for (room, extra) in Room::all().into_iter().zip(["DD", "CB", "BA", "AC"]) {
+        let back = unfolded.remove(&Position::Room(room, 1)).unwrap();
+        unfolded.insert(Position::Room(room, 3), back);
+        for (p, amphipod) in extra.chars().enumerate() {
+            unfolded.insert(Position::Room(room, p as u8 + 1), amphipod);
         }
+    }
+    unfolded
+}
 
-        open.remove(&current);
-        let moves = burrowverse.moves(current);
-
-        for (next, energy) in moves {
-            let tentative_g = g.get(&current).unwrap() + energy;
-            let current_g = g.entry(next).or_insert(usize::MAX);
-            if tentative_g < *current_g {
-                came_from.insert(next, current, energy);
-                *current_g = tentative_g;
-                open.push(
-                    next,
-                    Reverse(
-                        tentative_g + burrowverse.approx_cost(next) + came_from.path_len_to(next),
-                    ),
-                );
-            }
-        }
+pub struct Day23;
 
-        cycle_counter += 1;
-        let cycle_time = std::time::Instant::now() - start;
+impl Solution for Day23 {
+    type Input = BTreeMap<Position, char>;
 
-        stdout.queue(cursor::MoveTo(0, 0)).unwrap();
-        write!(stdout, "{}", burrowverse.format(current)).unwrap();
-        stdout.queue(cursor::MoveTo(0, 12)).unwrap();
-        write!(
-            stdout,
-            "cycles:      {:20}\nopen states: {:20}\nenergy:      {:20}\ncycle time:  {:20}µs",
-            cycle_counter,
-            open.len(),
-            g.get(&current).unwrap(),
-            cycle_time.as_micros()
-        )
-        .unwrap();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Burrowverse::parse_burrow(input)
     }
 
-    stdout.queue(cursor::Show).unwrap();
-    stdout.flush().unwrap();
+    fn part1(burrow: &Self::Input) -> Answer {
+        minimum_energy(&RefCell::default(), burrow.clone(), &mut Headless).into()
+    }
 
-    if !burrowverse.is_goal(current) {
-        println!("\nWE FAIL AFTER {}", cycle_counter);
+    fn part2(burrow: &Self::Input) -> Answer {
+        minimum_energy(&RefCell::default(), unfold(burrow), &mut Headless).into()
     }
 
-    println!();
-    println!(
-        "Energy used: {} ({})",
-        came_from.cost_from(current),
-        g.get(&current).unwrap(),
-    );
-    println!();
+    fn visualise(input: &str, part: u8) -> Result<Option<Answer>, ParseError> {
+        let burrow = Self::parse(input)?;
+        let burrow = match part {
+            1 => burrow,
+            2 => unfold(&burrow),
+            _ => return Ok(None),
+        };
 
-    for burrow in came_from.path_to(current) {
-        burrowverse.print(burrow);
+        let burrowverse = RefCell::default();
+        let mut terminal = Terminal::new(&burrowverse);
+        Ok(Some(
+            minimum_energy(&burrowverse, burrow, &mut terminal).into(),
+        ))
     }
 }
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day23::Day23>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -1,11 +1,10 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::str::FromStr;
 
-use anyhow::anyhow;
+use aoc_common::parse::{self, InputLine};
+use aoc_common::{Answer, ParseError, Solution};
 
 #[derive(Clone, Debug)]
-enum Variable {
+pub enum Variable {
     W,
     X,
     Y,
@@ -13,7 +12,7 @@ enum Variable {
 }
 
 impl FromStr for Variable {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -21,7 +20,7 @@ impl FromStr for Variable {
             "x" => Ok(Self::X),
             "y" => Ok(Self::Y),
             "z" => Ok(Self::Z),
-            _ => Err(anyhow!("unknown variable: {}", s)),
+            _ => Err(ParseError::new(1, 1, "a variable from w to z")),
         }
     }
 }
@@ -38,13 +37,13 @@ impl std::fmt::Display for Variable {
 }
 
 #[derive(Clone, Debug)]
-enum VariableOrImmediate {
+pub enum VariableOrImmediate {
     Variable(Variable),
     Immediate(i64),
 }
 
 impl FromStr for VariableOrImmediate {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Ok(variable) = Variable::from_str(s) {
@@ -52,7 +51,7 @@ impl FromStr for VariableOrImmediate {
         }
 
         Ok(Self::Immediate(i64::from_str(s).map_err(|_| {
-            anyhow!("couldn't parse as variable or immediate: {}", s)
+            ParseError::new(1, 1, "a variable or a number")
         })?))
     }
 }
@@ -67,7 +66,7 @@ impl std::fmt::Display for VariableOrImmediate {
 }
 
 #[derive(Clone, Debug)]
-enum Insn {
+pub enum Insn {
     Inp(Variable),
     Add(Variable, VariableOrImmediate),
     Mul(Variable, VariableOrImmediate),
@@ -77,18 +76,41 @@ enum Insn {
 }
 
 impl FromStr for Insn {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let insn = InputLine { number: 1, text: s };
         let parts: Vec<&str> = s.split_whitespace().collect();
-        match parts[0] {
-            "inp" => Ok(Self::Inp(parts[1].parse()?)),
-            "add" => Ok(Self::Add(parts[1].parse()?, parts[2].parse()?)),
-            "mul" => Ok(Self::Mul(parts[1].parse()?, parts[2].parse()?)),
-            "div" => Ok(Self::Div(parts[1].parse()?, parts[2].parse()?)),
-            "mod" => Ok(Self::Mod(parts[1].parse()?, parts[2].parse()?)),
-            "eql" => Ok(Self::Eql(parts[1].parse()?, parts[2].parse()?)),
-            _ => Err(anyhow!("unknown instruction: {}", s)),
+        let operand = |n: usize| {
+            parts
+                .get(n)
+                .copied()
+                .ok_or_else(|| ParseError::new(1, s.len() + 1, "another operand"))
+        };
+
+        match parts.first().copied().unwrap_or(s) {
+            "inp" => Ok(Self::Inp(insn.parse(operand(1)?)?)),
+            "add" => Ok(Self::Add(
+                insn.parse(operand(1)?)?,
+                insn.parse(operand(2)?)?,
+            )),
+            "mul" => Ok(Self::Mul(
+                insn.parse(operand(1)?)?,
+                insn.parse(operand(2)?)?,
+            )),
+            "div" => Ok(Self::Div(
+                insn.parse(operand(1)?)?,
+                insn.parse(operand(2)?)?,
+            )),
+            "mod" => Ok(Self::Mod(
+                insn.parse(operand(1)?)?,
+                insn.parse(operand(2)?)?,
+            )),
+            "eql" => Ok(Self::Eql(
+                insn.parse(operand(1)?)?,
+                insn.parse(operand(2)?)?,
+            )),
+            op => Err(insn.error(op, "an instruction")),
         }
     }
 }
@@ -107,11 +129,11 @@ impl std::fmt::Display for Insn {
 }
 
 #[derive(Debug, Default)]
-struct Alu {
-    w: i64,
-    x: i64,
-    y: i64,
-    z: i64,
+pub struct Alu {
+    pub w: i64,
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
 }
 
 impl Alu {
@@ -140,33 +162,34 @@ impl Alu {
         }
     }
 
-    fn run(&mut self, program: &[Insn], inputs: &[i64]) {
+    /// Runs `program`, stopping early if it asks for more than `inputs`.
+    pub fn run(&mut self, program: &[Insn], inputs: &[i64]) {
         let mut inputs = inputs.iter().copied();
         for insn in program {
             match insn {
                 Insn::Inp(v) => {
                     if let Some(input) = inputs.next() {
-                        self.set_variable(&v, input);
+                        self.set_variable(v, input);
                     } else {
                         break;
                     }
                 }
                 Insn::Add(a1, a2) => {
-                    self.set_variable(&a1, self.variable(&a1) + self.value(&a2));
+                    self.set_variable(a1, self.variable(a1) + self.value(a2));
                 }
                 Insn::Mul(a1, a2) => {
-                    self.set_variable(&a1, self.variable(&a1) * self.value(&a2));
+                    self.set_variable(a1, self.variable(a1) * self.value(a2));
                 }
                 Insn::Div(a1, a2) => {
-                    self.set_variable(&a1, self.variable(&a1) / self.value(&a2));
+                    self.set_variable(a1, self.variable(a1) / self.value(a2));
                 }
                 Insn::Mod(a1, a2) => {
-                    self.set_variable(&a1, self.variable(&a1) % self.value(&a2));
+                    self.set_variable(a1, self.variable(a1) % self.value(a2));
                 }
                 Insn::Eql(a1, a2) => {
                     self.set_variable(
-                        &a1,
-                        if self.variable(&a1) == self.value(&a2) {
+                        a1,
+                        if self.variable(a1) == self.value(a2) {
                             1
                         } else {
                             0
@@ -178,36 +201,47 @@ impl Alu {
     }
 }
 
-fn main() {
-    let file = File::open(std::env::args_os().nth(1).unwrap()).unwrap();
-    let reader = BufReader::new(file);
-
-    let program: Vec<Insn> = reader
-        .lines()
-        .map(|l| l.unwrap().parse().unwrap())
-        .collect();
-
-    for inputs in InputGenerator::<14>::new() {
+fn find_model_number(program: &[Insn], ascending: bool) -> i64 {
+    for inputs in InputGenerator::<14>::new(ascending) {
         let mut alu = Alu::default();
 
-        alu.run(&program, &inputs);
+        alu.run(program, &inputs);
 
         if alu.z == 0 {
-            for digit in &inputs {
-                print!("{}", digit);
-            }
-            println!(" -> w={} x={} y={} z={}", alu.w, alu.x, alu.y, alu.z);
-            break;
-        } else if alu.x == 0 {
-            for digit in &inputs {
-                print!("{}", digit);
-            }
-            println!(" -> w={} x={} y={} z={}", alu.w, alu.x, alu.y, alu.z);
+            return inputs.iter().fold(0, |number, digit| number * 10 + digit);
         }
     }
+
+    panic!("no valid model number");
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Insn>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let program: Vec<_> = parse::lines(input)
+            .map(|line| line.parse(line.text))
+            .collect::<Result<_, _>>()?;
+        if program.is_empty() {
+            return Err(ParseError::end(input, "an instruction"));
+        }
+
+        Ok(program)
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        find_model_number(program, false).into()
+    }
+
+    fn part2(program: &Self::Input) -> Answer {
+        find_model_number(program, true).into()
+    }
 }
 
 struct InputGenerator<const LENGTH: usize> {
+    ascending: bool,
     digits: [i64; LENGTH],
     prefix_4: Vec<[i64; 4]>,
     prefix_8: Vec<[i64; 4]>,
@@ -215,28 +249,35 @@ struct InputGenerator<const LENGTH: usize> {
 }
 
 impl<const LENGTH: usize> InputGenerator<LENGTH> {
-    fn new() -> Self {
-        let mut digits = [9; LENGTH];
-        let mut prefix_4: Vec<[i64; 4]> = Vec::from(PREFIX_4);
-        let mut prefix_8: Vec<[i64; 4]> = Vec::from(PREFIX_8);
-        let mut prefix_10: Vec<[i64; 2]> = Vec::from(PREFIX_10);
-        digits[..4].copy_from_slice(&prefix_4.pop().unwrap());
-        digits[4..8].copy_from_slice(&prefix_8.pop().unwrap());
-        digits[8..10].copy_from_slice(&prefix_10.pop().unwrap());
-        Self {
-            digits,
-            prefix_4,
-            prefix_8,
-            prefix_10,
+    fn new(ascending: bool) -> Self {
+        let mut generator = Self {
+            ascending,
+            digits: [if ascending { 1 } else { 9 }; LENGTH],
+            prefix_4: Self::table(&PREFIX_4, ascending),
+            prefix_8: Self::table(&PREFIX_8, ascending),
+            prefix_10: Self::table(&PREFIX_10, ascending),
+        };
+        generator.digits[..4].copy_from_slice(&generator.prefix_4.pop().unwrap());
+        generator.digits[4..8].copy_from_slice(&generator.prefix_8.pop().unwrap());
+        generator.digits[8..10].copy_from_slice(&generator.prefix_10.pop().unwrap());
+        generator
+    }
+
+    // The tables are in ascending order and are consumed from the end.
+    fn table<T: Copy>(table: &[T], ascending: bool) -> Vec<T> {
+        let mut table = Vec::from(table);
+        if ascending {
+            table.reverse();
         }
+        table
     }
 
     fn refresh_prefix_8(&mut self) {
-        self.prefix_8 = Vec::from(PREFIX_8);
+        self.prefix_8 = Self::table(&PREFIX_8, self.ascending);
     }
 
     fn refresh_prefix_10(&mut self) {
-        self.prefix_10 = Vec::from(PREFIX_10);
+        self.prefix_10 = Self::table(&PREFIX_10, self.ascending);
     }
 }
 
@@ -244,28 +285,36 @@ impl<const LENGTH: usize> Iterator for InputGenerator<LENGTH> {
     type Item = [i64; LENGTH];
 
     fn next(&mut self) -> Option<Self::Item> {
-        let digits = self.digits.clone();
+        let digits = self.digits;
         let mut next_prefix = true;
 
         for pos in (10..self.digits.len()).rev() {
-            self.digits[pos] -= 1;
-            if self.digits[pos] == 0 {
-                self.digits[pos] = 9;
+            if self.ascending {
+                self.digits[pos] += 1;
+                if self.digits[pos] == 10 {
+                    self.digits[pos] = 1;
+                    continue;
+                }
             } else {
-                next_prefix = false;
-                break;
+                self.digits[pos] -= 1;
+                if self.digits[pos] == 0 {
+                    self.digits[pos] = 9;
+                    continue;
+                }
             }
+            next_prefix = false;
+            break;
         }
 
         if next_prefix {
-            if self.prefix_4.is_empty() {
-                return None;
-            }
-            if self.prefix_8.is_empty() {
-                self.digits[..4].copy_from_slice(&self.prefix_4.pop().unwrap());
-                self.refresh_prefix_8();
-            }
             if self.prefix_10.is_empty() {
+                if self.prefix_8.is_empty() {
+                    if self.prefix_4.is_empty() {
+                        return None;
+                    }
+                    self.digits[..4].copy_from_slice(&self.prefix_4.pop().unwrap());
+                    self.refresh_prefix_8();
+                }
                 self.digits[4..8].copy_from_slice(&self.prefix_8.pop().unwrap());
                 self.refresh_prefix_10();
             }
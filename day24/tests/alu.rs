@@ -0,0 +1,38 @@
+use aoc_common::Solution;
+use day24::{Alu, Day24};
+
+fn run(program: &str, inputs: &[i64]) -> Alu {
+    let program = Day24::parse(program).unwrap();
+    let mut alu = Alu::default();
+    alu.run(&program, inputs);
+    alu
+}
+
+#[test]
+fn negate() {
+    let program = include_str!("../test-input-1");
+    assert_eq!(run(program, &[5]).x, -5);
+    assert_eq!(run(program, &[-3]).x, 3);
+}
+
+#[test]
+fn three_times() {
+    let program = include_str!("../test-input-2");
+    assert_eq!(run(program, &[2, 6]).z, 1);
+    assert_eq!(run(program, &[2, 7]).z, 0);
+}
+
+#[test]
+fn binary() {
+    let alu = run(include_str!("../test-input-3"), &[13]);
+    assert_eq!((alu.w, alu.x, alu.y, alu.z), (1, 1, 0, 1));
+
+    let alu = run(include_str!("../test-input-3"), &[6]);
+    assert_eq!((alu.w, alu.x, alu.y, alu.z), (0, 1, 1, 0));
+}
+
+#[test]
+fn needs_a_program() {
+    let e = Day24::parse("").unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (1, "an instruction"));
+}
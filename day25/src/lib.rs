@@ -0,0 +1,83 @@
+use aoc_common::automaton::{Automaton, Boundary, Rule, View};
+use aoc_common::grid::Grid;
+use aoc_common::{parse, Answer, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum SeaCucumber {
+    East,
+    South,
+}
+
+impl TryFrom<char> for SeaCucumber {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '>' => Ok(Self::East),
+            'v' => Ok(Self::South),
+            _ => Err(c),
+        }
+    }
+}
+
+impl std::fmt::Display for SeaCucumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::East => write!(f, ">"),
+            Self::South => write!(f, "v"),
+        }
+    }
+}
+
+pub struct Floor(Grid<Option<SeaCucumber>>);
+
+/// The east-facing herd moves, then the south-facing one. A cucumber moves
+/// if the space in front of it is empty, all of its herd at once.
+struct Migration;
+
+impl Rule<Option<SeaCucumber>> for Migration {
+    const PHASES: usize = 2;
+
+    fn next(&self, phase: usize, view: &View<Option<SeaCucumber>>) -> Option<SeaCucumber> {
+        let (herd, (dx, dy)) = match phase {
+            0 => (SeaCucumber::East, (1, 0)),
+            _ => (SeaCucumber::South, (0, 1)),
+        };
+        let here = *view.cell();
+
+        if here == Some(herd) && view.at(dx, dy).unwrap().is_none() {
+            None
+        } else if here.is_none() && *view.at(-dx, -dy).unwrap() == Some(herd) {
+            Some(herd)
+        } else {
+            here
+        }
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Floor;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let floor = Grid::parse(parse::lines(input), "> or v or .", |c| match c {
+            '.' => Some(None),
+            c => SeaCucumber::try_from(c).ok().map(Some),
+        })?;
+
+        if floor.is_empty() {
+            return Err(ParseError::end(input, "a row of sea cucumbers"));
+        }
+
+        Ok(Floor(floor))
+    }
+
+    fn part1(floor: &Self::Input) -> Answer {
+        Automaton::new(floor.0.clone(), Boundary::Wrapping)
+            .run_until_stable(&Migration)
+            .into()
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_common::examples::check::<day25::Day25>(env!("CARGO_MANIFEST_DIR"));
+}